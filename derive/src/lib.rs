@@ -0,0 +1,215 @@
+//! Derive macros for [keccak-const](https://crates.io/crates/keccak-const)
+//!
+//! The macros are re-exported by `keccak-const` with the `derive` feature.
+
+use proc_macro::TokenStream;
+use proc_macro2::Span;
+use quote::quote;
+use syn::parse_macro_input;
+use syn::spanned::Spanned;
+use syn::Data;
+use syn::DeriveInput;
+use syn::Fields;
+use syn::LitByteStr;
+use syn::LitStr;
+
+/// Derives `Eip712` and `Eip712Type` for a struct with named fields
+///
+/// The struct implements `Eip712` with its `encodeType`, `typeHash` and
+/// `hash_struct`, and `Eip712Type` so it can be a member of other structs.
+/// Referenced struct types are collected from the member types.
+///
+/// Members are named after the fields converted to camelCase and their types
+/// are the `Eip712Type::TYPE_NAME` of the field types, which also encode the
+/// values. The struct and its members can be renamed, and a member type can be
+/// overridden with an integer type of the same signedness as the field type,
+/// whose range is checked when the value is encoded:
+///
+/// ```rust
+/// # use keccak_const::address::Address;
+/// # use keccak_const::eip712::Eip712;
+/// #[derive(Eip712)]
+/// #[eip712(name = "Order")]
+/// struct LimitOrder {
+///     maker: Address,
+///     #[eip712(type = "uint96")]
+///     amount: u128,
+///     #[eip712(rename = "expiry")]
+///     valid_until: u64,
+/// }
+///
+/// assert_eq!(
+///     "Order(address maker,uint96 amount,uint64 expiry)",
+///     LimitOrder::ENCODE_TYPE.as_str()
+/// );
+/// ```
+#[proc_macro_derive(Eip712, attributes(eip712))]
+pub fn derive_eip712(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    match expand_eip712(input) {
+        Ok(tokens) => tokens.into(),
+        Err(error) => error.to_compile_error().into(),
+    }
+}
+
+/// The options of an `#[eip712(...)]` attribute
+#[derive(Default)]
+struct Options {
+    name: Option<LitStr>,
+    rename: Option<LitStr>,
+    ty: Option<LitStr>,
+}
+
+impl Options {
+    fn parse(attrs: &[syn::Attribute]) -> syn::Result<Options> {
+        let mut options = Options::default();
+        for attr in attrs.iter().filter(|attr| attr.path().is_ident("eip712")) {
+            attr.parse_nested_meta(|meta| {
+                let slot = if meta.path.is_ident("name") {
+                    &mut options.name
+                } else if meta.path.is_ident("rename") {
+                    &mut options.rename
+                } else if meta.path.is_ident("type") {
+                    &mut options.ty
+                } else {
+                    return Err(meta.error("expected `name`, `rename` or `type`"));
+                };
+                if slot.is_some() {
+                    return Err(meta.error("duplicate option"));
+                }
+                *slot = Some(meta.value()?.parse()?);
+                Ok(())
+            })?;
+        }
+        Ok(options)
+    }
+}
+
+/// Converts a snake_case field name to camelCase
+fn to_camel_case(name: &str) -> String {
+    let mut camel_case = String::with_capacity(name.len());
+    let mut upper = false;
+    for c in name.chars() {
+        if c == '_' {
+            upper = !camel_case.is_empty();
+        } else if upper {
+            camel_case.extend(c.to_uppercase());
+            upper = false;
+        } else {
+            camel_case.push(c);
+        }
+    }
+    camel_case
+}
+
+fn byte_str(s: &str, span: Span) -> LitByteStr {
+    LitByteStr::new(s.as_bytes(), span)
+}
+
+fn expand_eip712(input: DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
+    let Data::Struct(data) = &input.data else {
+        return Err(syn::Error::new(
+            input.ident.span(),
+            "`Eip712` can only be derived for structs",
+        ));
+    };
+    let Fields::Named(fields) = &data.fields else {
+        return Err(syn::Error::new(
+            data.fields.span(),
+            "`Eip712` can only be derived for structs with named fields",
+        ));
+    };
+
+    let options = Options::parse(&input.attrs)?;
+    if let Some(option) = options.rename.as_ref().or(options.ty.as_ref()) {
+        return Err(syn::Error::new(
+            option.span(),
+            "only `name` applies to the struct",
+        ));
+    }
+    let name = match &options.name {
+        Some(name) => name.value(),
+        None => input.ident.to_string(),
+    };
+
+    let mut definition = Vec::new();
+    let mut struct_types = Vec::new();
+    let mut members = Vec::new();
+    for (i, field) in fields.named.iter().enumerate() {
+        let field_options = Options::parse(&field.attrs)?;
+        if let Some(name) = &field_options.name {
+            return Err(syn::Error::new(
+                name.span(),
+                "use `rename` to rename a member",
+            ));
+        }
+        let ident = field.ident.as_ref().unwrap();
+        let ty = &field.ty;
+        let member_name = match &field_options.rename {
+            Some(rename) => rename.value(),
+            None => to_camel_case(ident.to_string().trim_start_matches("r#")),
+        };
+
+        let separator = byte_str(if i == 0 { "" } else { "," }, ident.span());
+        let member_name = byte_str(&format!(" {member_name}"), ident.span());
+        let (member_type, encode_data) = match &field_options.ty {
+            Some(override_type) => (
+                quote! {
+                    .push_type_override(
+                        &<#ty as ::keccak_const::eip712::Eip712Type>::TYPE_NAME,
+                        #override_type,
+                    )
+                },
+                quote!(::keccak_const::eip712::encode_data_as(&self.#ident, #override_type)),
+            ),
+            None => (
+                quote!(.push_name(&<#ty as ::keccak_const::eip712::Eip712Type>::TYPE_NAME)),
+                quote!(<#ty as ::keccak_const::eip712::Eip712Type>::encode_data(&self.#ident)),
+            ),
+        };
+        definition.push(quote! {
+            .push_bytes(#separator)
+            #member_type
+            .push_bytes(#member_name)
+        });
+        struct_types.push(quote! {
+            .union(&<#ty as ::keccak_const::eip712::Eip712Type>::STRUCT_TYPES)
+        });
+        members.push(quote!(.push_word(&#encode_data)));
+    }
+
+    let ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let type_name = byte_str(&name, ident.span());
+    let open = byte_str(&format!("{name}("), ident.span());
+
+    Ok(quote! {
+        impl #impl_generics ::keccak_const::eip712::Eip712Type for #ident #ty_generics #where_clause {
+            const TYPE_NAME: ::keccak_const::sol_type::AbiName =
+                ::keccak_const::sol_type::AbiName::new().push_bytes(#type_name);
+            const STRUCT_TYPES: ::keccak_const::eip712::StructTypes =
+                ::keccak_const::eip712::StructTypes::new()
+                    .insert(
+                        &::keccak_const::eip712::StructDefinition::new()
+                            .push_bytes(#open)
+                            #(#definition)*
+                            .push_bytes(b")"),
+                    )
+                    #(#struct_types)*;
+
+            fn encode_data(&self) -> [u8; 32] {
+                <Self as ::keccak_const::eip712::Eip712>::hash_struct(self)
+            }
+        }
+
+        impl #impl_generics ::keccak_const::eip712::Eip712 for #ident #ty_generics #where_clause {
+            fn hash_struct(&self) -> [u8; 32] {
+                ::keccak_const::eip712::HashStruct::new(
+                    &<Self as ::keccak_const::eip712::Eip712>::TYPE_HASH,
+                )
+                #(#members)*
+                .finalize()
+            }
+        }
+    })
+}
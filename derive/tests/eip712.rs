@@ -0,0 +1,307 @@
+//! The `Mail` values are those of the example in EIP-712, the others are
+//! checked against the `keccak_const::eip712` builders
+
+use keccak_const::address::Address;
+use keccak_const::eip712;
+use keccak_const::eip712::Eip712;
+use keccak_const::eip712::EncodeType;
+use keccak_const::eip712::HashStruct;
+use keccak_const::sol_type::Bytes;
+use keccak_const::sol_type::FixedBytes;
+use keccak_const::sol_type::U256;
+
+#[derive(Eip712)]
+struct Mail {
+    from: Person,
+    to: Person,
+    contents: String,
+}
+
+#[derive(Eip712)]
+struct Person {
+    name: String,
+    wallet: Address,
+}
+
+#[derive(Eip712)]
+struct Permit {
+    owner: Address,
+    spender: Address,
+    value: U256,
+    nonce: U256,
+    deadline: U256,
+}
+
+/// A struct whose definition is longer than an `AbiName`
+#[derive(Eip712)]
+struct WideOrder {
+    owner: Address,
+    sell_token: Address,
+    buy_token: Address,
+    receiver: Address,
+    sell_amount: U256,
+    buy_amount: U256,
+    valid_to: u32,
+    app_data: FixedBytes<32>,
+    fee_amount: U256,
+    kind: String,
+    partially_fillable: bool,
+    sell_token_balance: String,
+    buy_token_balance: String,
+    nonce: U256,
+}
+
+#[derive(Eip712)]
+struct Group<'a> {
+    name: &'a str,
+    members: Vec<Address>,
+    rank: i8,
+    open: bool,
+    data: Bytes<'a>,
+    tag: FixedBytes<4>,
+}
+
+#[derive(Eip712)]
+struct Transaction {
+    from: Person2,
+    to: Vec<Person2>,
+    assets: [Asset; 2],
+}
+
+#[derive(Eip712)]
+#[eip712(name = "Person")]
+struct Person2 {
+    name: String,
+    wallet: Wallet,
+}
+
+#[derive(Eip712)]
+struct Wallet {
+    addr: Address,
+    holdings: Vec<Asset>,
+}
+
+#[derive(Eip712)]
+struct Asset {
+    token: Address,
+    amount: U256,
+}
+
+#[derive(Eip712)]
+#[eip712(name = "Order")]
+struct LimitOrder {
+    maker_address: Address,
+    #[eip712(type = "uint96")]
+    amount: u128,
+    #[eip712(rename = "expiry")]
+    valid_until: u64,
+}
+
+const COW: Address = Address([
+    0xcd, 0x2a, 0x3d, 0x9f, 0x93, 0x8e, 0x13, 0xcd, 0x94, 0x7e, 0xc0, 0x5a, 0xbc, 0x7f, 0xe7, 0x34,
+    0xdf, 0x8d, 0xd8, 0x26,
+]);
+
+const BOB: Address = Address([0xbb; 20]);
+
+#[test]
+fn mail() {
+    let mail = Mail {
+        from: Person {
+            name: "Cow".into(),
+            wallet: COW,
+        },
+        to: Person {
+            name: "Bob".into(),
+            wallet: BOB,
+        },
+        contents: "Hello, Bob!".into(),
+    };
+
+    assert_eq!(
+        "Mail(Person from,Person to,string contents)Person(string name,address wallet)",
+        Mail::ENCODE_TYPE.as_str()
+    );
+    assert_eq!(
+        [
+            0xa0, 0xce, 0xde, 0xb2, 0xdc, 0x28, 0x0b, 0xa3, 0x9b, 0x85, 0x75, 0x46, 0xd7, 0x4f,
+            0x55, 0x49, 0xc3, 0xa1, 0xd7, 0xbd, 0xc2, 0xdd, 0x96, 0xbf, 0x88, 0x1f, 0x76, 0x10,
+            0x8e, 0x23, 0xda, 0xc2,
+        ],
+        Mail::TYPE_HASH
+    );
+    assert_eq!(
+        "Person(string name,address wallet)",
+        Person::ENCODE_TYPE.as_str()
+    );
+    assert_eq!(
+        [
+            0xc5, 0x2c, 0x0e, 0xe5, 0xd8, 0x42, 0x64, 0x47, 0x18, 0x06, 0x29, 0x0a, 0x3f, 0x2c,
+            0x4c, 0xec, 0xfc, 0x54, 0x90, 0x62, 0x6b, 0xf9, 0x12, 0xd0, 0x1f, 0x24, 0x0d, 0x7a,
+            0x27, 0x4b, 0x37, 0x1e,
+        ],
+        mail.hash_struct()
+    );
+}
+
+#[test]
+fn permit() {
+    assert_eq!(
+        [
+            0x6e, 0x71, 0xed, 0xae, 0x12, 0xb1, 0xb9, 0x7f, 0x4d, 0x1f, 0x60, 0x37, 0x0f, 0xef,
+            0x10, 0x10, 0x5f, 0xa2, 0xfa, 0xae, 0x01, 0x26, 0x11, 0x4a, 0x16, 0x9c, 0x64, 0x84,
+            0x5d, 0x61, 0x26, 0xc9,
+        ],
+        Permit::TYPE_HASH
+    );
+}
+
+#[test]
+fn wide_struct() {
+    const DEFINITION: &str = concat!(
+        "WideOrder(address owner,address sellToken,address buyToken,address receiver,",
+        "uint256 sellAmount,uint256 buyAmount,uint32 validTo,bytes32 appData,",
+        "uint256 feeAmount,string kind,bool partiallyFillable,string sellTokenBalance,",
+        "string buyTokenBalance,uint256 nonce)",
+    );
+
+    assert!(DEFINITION.len() > keccak_const::sol_type::MAX_ABI_NAME_LEN);
+    assert_eq!(DEFINITION, WideOrder::ENCODE_TYPE.as_str());
+    assert_eq!(eip712::type_hash(&[DEFINITION]), WideOrder::TYPE_HASH);
+}
+
+#[test]
+fn member_types() {
+    let group = Group {
+        name: "devs",
+        members: vec![Address([0x11; 20]), Address([0x22; 20])],
+        rank: -2,
+        open: true,
+        data: Bytes(&[0x01, 0x02]),
+        tag: FixedBytes([0xde, 0xad, 0xbe, 0xef]),
+    };
+
+    assert_eq!(
+        "Group(string name,address[] members,int8 rank,bool open,bytes data,bytes4 tag)",
+        Group::ENCODE_TYPE.as_str()
+    );
+    assert_eq!(
+        [
+            0x85, 0xe9, 0x54, 0x3f, 0x42, 0x25, 0x97, 0xb5, 0x26, 0x8a, 0x69, 0x83, 0x79, 0xb9,
+            0xa3, 0xa7, 0xc2, 0x58, 0x3b, 0x5b, 0x45, 0xf7, 0x36, 0x35, 0xe5, 0x03, 0xcf, 0x31,
+            0x92, 0xd1, 0x1e, 0xa0,
+        ],
+        group.hash_struct()
+    );
+}
+
+#[test]
+fn nested_types() {
+    const TYPES: [&str; 4] = [
+        "Transaction(Person from,Person[] to,Asset[2] assets)",
+        "Person(string name,Wallet wallet)",
+        "Wallet(address addr,Asset[] holdings)",
+        "Asset(address token,uint256 amount)",
+    ];
+    const ENCODE_TYPE: EncodeType<256> = EncodeType::new(&TYPES);
+    const PERSON_TYPE_HASH: [u8; 32] = eip712::type_hash(&[TYPES[1], TYPES[2], TYPES[3]]);
+    const WALLET_TYPE_HASH: [u8; 32] = eip712::type_hash(&[TYPES[2], TYPES[3]]);
+    const ASSET_TYPE_HASH: [u8; 32] = eip712::type_hash(&[TYPES[3]]);
+
+    let asset = |amount: u8| {
+        let mut word = [0; 32];
+        word[31] = amount;
+        Asset {
+            token: Address([0x11; 20]),
+            amount: U256(word),
+        }
+    };
+    let person = |name: &str| Person2 {
+        name: name.into(),
+        wallet: Wallet {
+            addr: BOB,
+            holdings: vec![asset(1)],
+        },
+    };
+    let transaction = Transaction {
+        from: person("Cow"),
+        to: vec![person("Bob"), person("Eve")],
+        assets: [asset(2), asset(3)],
+    };
+
+    let asset_hash = |amount: u8| {
+        HashStruct::new(&ASSET_TYPE_HASH)
+            .push_address(&Address([0x11; 20]))
+            .push_uint::<256>(amount as u128)
+            .finalize()
+    };
+    let person_hash = |name| {
+        HashStruct::new(&PERSON_TYPE_HASH)
+            .push_string(name)
+            .push_struct(
+                &HashStruct::new(&WALLET_TYPE_HASH)
+                    .push_address(&BOB)
+                    .push_array(&[asset_hash(1)])
+                    .finalize(),
+            )
+            .finalize()
+    };
+
+    assert_eq!(ENCODE_TYPE.as_str(), Transaction::ENCODE_TYPE.as_str());
+    assert_eq!(ENCODE_TYPE.type_hash(), Transaction::TYPE_HASH);
+    assert_eq!(
+        HashStruct::new(&ENCODE_TYPE.type_hash())
+            .push_struct(&person_hash("Cow"))
+            .push_array(&[person_hash("Bob"), person_hash("Eve")])
+            .push_array(&[asset_hash(2), asset_hash(3)])
+            .finalize(),
+        transaction.hash_struct()
+    );
+}
+
+#[test]
+fn attributes() {
+    let order = LimitOrder {
+        maker_address: BOB,
+        amount: 5,
+        valid_until: 6,
+    };
+
+    assert_eq!(
+        "Order(address makerAddress,uint96 amount,uint64 expiry)",
+        LimitOrder::ENCODE_TYPE.as_str()
+    );
+    assert_eq!(
+        HashStruct::new(&LimitOrder::TYPE_HASH)
+            .push_address(&BOB)
+            .push_uint::<96>(5)
+            .push_uint::<64>(6)
+            .finalize(),
+        order.hash_struct()
+    );
+}
+
+#[test]
+#[should_panic = "value out of range for uint96"]
+fn type_override_out_of_range() {
+    let order = LimitOrder {
+        maker_address: BOB,
+        amount: 1 << 96,
+        valid_until: 6,
+    };
+    order.hash_struct();
+}
+
+#[test]
+#[should_panic = "type override must be an integer type of the same signedness as the field"]
+fn type_override_signedness() {
+    eip712::StructDefinition::new()
+        .push_type_override(&<u128 as eip712::Eip712Type>::TYPE_NAME, "int96");
+}
+
+#[test]
+#[should_panic = "invalid EIP-712 member type"]
+fn type_override_invalid() {
+    eip712::StructDefinition::new()
+        .push_type_override(&<u128 as eip712::Eip712Type>::TYPE_NAME, "uint96 amount");
+}
@@ -0,0 +1,461 @@
+//! Solidity ABI encodings
+//!
+//! [`Encode`] builds `abi.encode(...)` of static types into a fixed-size
+//! array, optionally prefixed with a function selector to form calldata.
+//! Every value occupies a 32-byte word and static tuples and fixed-size arrays
+//! are encoded in place, so they are pushed element by element.
+//!
+//! [`EncodePacked`] reproduces `keccak256(abi.encodePacked(...))`, feeding
+//! each argument straight into [`Keccak256`]: value types are written with
+//! their own width and without padding, `bytes` and `string` are written
+//! as-is, and array elements are padded to 32 bytes.
+//!
+//! With the `alloc` feature, `encode` and `decode` handle dynamic types such
+//! as `bytes`, `string`, `T[]` and dynamic tuples at runtime, and
+//! `decode_revert` decodes revert data.
+//!
+//! # Examples
+//!
+//! ```rust
+//! # use keccak_const::abi::Encode;
+//! # use keccak_const::address::Address;
+//! // abi.encodeWithSignature("transfer(address,uint256)", to, 1000)
+//! const CALLDATA: [u8; 68] = Encode::with_signature("transfer(address,uint256)")
+//!     .push_address(&Address([0x11; 20]))
+//!     .push_uint::<256>(1000)
+//!     .finalize();
+//!
+//! assert_eq!([0xa9, 0x05, 0x9c, 0xbb], CALLDATA[..4]);
+//! assert_eq!([0x03, 0xe8], CALLDATA[66..]);
+//! ```
+//!
+//! ```rust
+//! # use keccak_const::abi::EncodePacked;
+//! // keccak256(abi.encodePacked(int16(-1), bytes1(0x42), uint16(0x03), "Hello, world!"))
+//! const HASH: [u8; 32] = EncodePacked::new()
+//!     .push_int::<16>(-1)
+//!     .push_bytes_n(&[0x42])
+//!     .push_uint::<16>(0x03)
+//!     .push_string("Hello, world!")
+//!     .finalize();
+//! ```
+
+#[cfg(feature = "alloc")]
+mod dynamic;
+
+use crate::address::Address;
+use crate::solidity::function_selector;
+use crate::Keccak256;
+#[cfg(feature = "alloc")]
+pub use dynamic::decode;
+#[cfg(feature = "alloc")]
+pub use dynamic::decode_revert;
+#[cfg(feature = "alloc")]
+pub use dynamic::encode;
+#[cfg(feature = "alloc")]
+pub use dynamic::encode_with_selector;
+#[cfg(feature = "alloc")]
+pub use dynamic::DecodeError;
+#[cfg(feature = "alloc")]
+pub use dynamic::Revert;
+#[cfg(feature = "alloc")]
+pub use dynamic::Type;
+#[cfg(feature = "alloc")]
+pub use dynamic::Value;
+
+/// Asserts that `BITS` is a valid width of `uintN` or `intN`
+#[allow(clippy::manual_is_multiple_of)]
+const fn check_width<const BITS: usize>() {
+    assert!(
+        BITS % 8 == 0 && 8 <= BITS && BITS <= 256,
+        "integer width must be a multiple of 8 between 8 and 256 bits"
+    );
+}
+
+/// The 32-byte ABI word of a `uintN` value
+pub(crate) const fn uint_word<const BITS: usize>(value: u128) -> [u8; 32] {
+    check_width::<BITS>();
+    assert!(
+        BITS >= 128 || value >> BITS == 0,
+        "value out of range for uintN"
+    );
+    let bytes = value.to_be_bytes();
+    let mut word = [0; 32];
+    let mut i = 0;
+    while i < bytes.len() {
+        word[16 + i] = bytes[i];
+        i += 1;
+    }
+    word
+}
+
+/// The 32-byte ABI word of an `intN` value, sign-extended
+pub(crate) const fn int_word<const BITS: usize>(value: i128) -> [u8; 32] {
+    check_width::<BITS>();
+    assert!(
+        BITS >= 128 || value >> (BITS - 1) == 0 || value >> (BITS - 1) == -1,
+        "value out of range for intN"
+    );
+    let bytes = value.to_be_bytes();
+    let mut word = if value < 0 { [0xff; 32] } else { [0; 32] };
+    let mut i = 0;
+    while i < bytes.len() {
+        word[16 + i] = bytes[i];
+        i += 1;
+    }
+    word
+}
+
+/// The 32-byte ABI word of an `address` value
+pub(crate) const fn address_word(address: &Address) -> [u8; 32] {
+    let mut word = [0; 32];
+    let mut i = 0;
+    while i < address.0.len() {
+        word[12 + i] = address.0[i];
+        i += 1;
+    }
+    word
+}
+
+/// The 32-byte ABI word of a `bytesN` value, right-padded
+pub(crate) const fn bytes_n_word(value: &[u8]) -> [u8; 32] {
+    assert!(
+        !value.is_empty() && value.len() <= 32,
+        "bytesN must be 1 to 32 bytes long"
+    );
+    let mut word = [0; 32];
+    let mut i = 0;
+    while i < value.len() {
+        word[i] = value[i];
+        i += 1;
+    }
+    word
+}
+
+/// The encoding `abi.encode(...)` of static types, optionally prefixed with a
+/// function selector
+///
+/// `LEN` is the length of the complete encoding, 32 bytes per value plus 4
+/// bytes for the selector.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Encode<const LEN: usize> {
+    bytes: [u8; LEN],
+    pos: usize,
+}
+
+impl<const LEN: usize> Encode<LEN> {
+    /// Constructs a new encoding, `abi.encode(...)`
+    #[allow(clippy::manual_is_multiple_of)]
+    pub const fn new() -> Encode<LEN> {
+        assert!(LEN % 32 == 0, "LEN must be a multiple of 32");
+        Encode {
+            bytes: [0; LEN],
+            pos: 0,
+        }
+    }
+
+    /// Constructs new calldata starting with `selector`,
+    /// `abi.encodeWithSelector(selector, ...)`
+    #[allow(clippy::manual_is_multiple_of)]
+    pub const fn with_selector(selector: [u8; 4]) -> Encode<LEN> {
+        assert!(
+            LEN >= 4 && (LEN - 4) % 32 == 0,
+            "LEN must be 4 plus a multiple of 32"
+        );
+        let mut bytes = [0; LEN];
+        bytes[0] = selector[0];
+        bytes[1] = selector[1];
+        bytes[2] = selector[2];
+        bytes[3] = selector[3];
+        Encode { bytes, pos: 4 }
+    }
+
+    /// Constructs new calldata starting with the selector of a canonical
+    /// function signature, `abi.encodeWithSignature(signature, ...)`
+    pub const fn with_signature(signature: &str) -> Encode<LEN> {
+        Encode::with_selector(function_selector(signature))
+    }
+
+    /// Appends a 32-byte value such as a `uint256`, `int256` or `bytes32`
+    pub const fn push_word(mut self, word: &[u8; 32]) -> Self {
+        assert!(self.pos + 32 <= LEN, "encoding exceeds LEN");
+        let mut i = 0;
+        while i < word.len() {
+            self.bytes[self.pos + i] = word[i];
+            i += 1;
+        }
+        self.pos += 32;
+        self
+    }
+
+    /// Appends an `address`
+    pub const fn push_address(self, address: &Address) -> Self {
+        self.push_word(&address_word(address))
+    }
+
+    /// Appends a `uintN` where `N` is `BITS`
+    ///
+    /// Use [`push_word`](Self::push_word) for values that do not fit in 128
+    /// bits.
+    pub const fn push_uint<const BITS: usize>(self, value: u128) -> Self {
+        self.push_word(&uint_word::<BITS>(value))
+    }
+
+    /// Appends an `intN` where `N` is `BITS`
+    ///
+    /// Use [`push_word`](Self::push_word) for values that do not fit in 128
+    /// bits.
+    pub const fn push_int<const BITS: usize>(self, value: i128) -> Self {
+        self.push_word(&int_word::<BITS>(value))
+    }
+
+    /// Appends a `bytesN` where `N` is the length of `value`
+    pub const fn push_bytes_n(self, value: &[u8]) -> Self {
+        self.push_word(&bytes_n_word(value))
+    }
+
+    /// Appends a `bool`
+    pub const fn push_bool(self, value: bool) -> Self {
+        self.push_word(&uint_word::<8>(value as u128))
+    }
+
+    /// Appends an `address[k]`
+    pub const fn push_address_array(mut self, elements: &[Address]) -> Self {
+        let mut i = 0;
+        while i < elements.len() {
+            self = self.push_address(&elements[i]);
+            i += 1;
+        }
+        self
+    }
+
+    /// Appends a `uintN[k]` where `N` is `BITS`
+    pub const fn push_uint_array<const BITS: usize>(mut self, elements: &[u128]) -> Self {
+        let mut i = 0;
+        while i < elements.len() {
+            self = self.push_uint::<BITS>(elements[i]);
+            i += 1;
+        }
+        self
+    }
+
+    /// Appends an `intN[k]` where `N` is `BITS`
+    pub const fn push_int_array<const BITS: usize>(mut self, elements: &[i128]) -> Self {
+        let mut i = 0;
+        while i < elements.len() {
+            self = self.push_int::<BITS>(elements[i]);
+            i += 1;
+        }
+        self
+    }
+
+    /// Appends a `bool[k]`
+    pub const fn push_bool_array(mut self, elements: &[bool]) -> Self {
+        let mut i = 0;
+        while i < elements.len() {
+            self = self.push_bool(elements[i]);
+            i += 1;
+        }
+        self
+    }
+
+    /// Appends a `bytesN[k]`
+    pub const fn push_bytes_n_array(mut self, elements: &[&[u8]]) -> Self {
+        let mut i = 0;
+        while i < elements.len() {
+            self = self.push_bytes_n(elements[i]);
+            i += 1;
+        }
+        self
+    }
+
+    /// Appends an array of 32-byte elements such as `uint256[k]` or
+    /// `bytes32[k]`
+    pub const fn push_word_array(mut self, elements: &[[u8; 32]]) -> Self {
+        let mut i = 0;
+        while i < elements.len() {
+            self = self.push_word(&elements[i]);
+            i += 1;
+        }
+        self
+    }
+
+    /// Returns the encoding
+    pub const fn finalize(self) -> [u8; LEN] {
+        assert!(self.pos == LEN, "encoding is shorter than LEN");
+        self.bytes
+    }
+}
+
+impl<const LEN: usize> Default for Encode<LEN> {
+    fn default() -> Self {
+        Encode::new()
+    }
+}
+
+/// Keccak-256 hash of the packed encoding `abi.encodePacked(...)`
+#[derive(Clone)]
+pub struct EncodePacked {
+    keccak: Keccak256,
+}
+
+impl EncodePacked {
+    /// Constructs a new encoder with no arguments
+    pub const fn new() -> EncodePacked {
+        EncodePacked {
+            keccak: Keccak256::new(),
+        }
+    }
+
+    /// Continues the encoding after the bytes already absorbed by `keccak`
+    pub const fn from_keccak(keccak: Keccak256) -> EncodePacked {
+        EncodePacked { keccak }
+    }
+
+    /// Returns the underlying hasher
+    pub const fn into_keccak(self) -> Keccak256 {
+        self.keccak
+    }
+
+    /// Appends an `address`
+    pub const fn push_address(self, address: &Address) -> Self {
+        EncodePacked {
+            keccak: self.keccak.update(&address.0),
+        }
+    }
+
+    /// Appends a `uintN` where `N` is `BITS`
+    ///
+    /// Use [`push_word`](Self::push_word) for values that do not fit in 128
+    /// bits.
+    pub const fn push_uint<const BITS: usize>(self, value: u128) -> Self {
+        let word = uint_word::<BITS>(value);
+        EncodePacked {
+            keccak: self.keccak.update(word.split_at(32 - BITS / 8).1),
+        }
+    }
+
+    /// Appends an `intN` where `N` is `BITS`
+    ///
+    /// Use [`push_word`](Self::push_word) for values that do not fit in 128
+    /// bits.
+    pub const fn push_int<const BITS: usize>(self, value: i128) -> Self {
+        let word = int_word::<BITS>(value);
+        EncodePacked {
+            keccak: self.keccak.update(word.split_at(32 - BITS / 8).1),
+        }
+    }
+
+    /// Appends a 32-byte value such as a `uint256`, `int256` or `bytes32`
+    pub const fn push_word(self, word: &[u8; 32]) -> Self {
+        EncodePacked {
+            keccak: self.keccak.update(word),
+        }
+    }
+
+    /// Appends a `bytesN` where `N` is the length of `value`
+    pub const fn push_bytes_n(self, value: &[u8]) -> Self {
+        assert!(
+            !value.is_empty() && value.len() <= 32,
+            "bytesN must be 1 to 32 bytes long"
+        );
+        EncodePacked {
+            keccak: self.keccak.update(value),
+        }
+    }
+
+    /// Appends a `bool`
+    pub const fn push_bool(self, value: bool) -> Self {
+        EncodePacked {
+            keccak: self.keccak.update(&[value as u8]),
+        }
+    }
+
+    /// Appends a dynamic `bytes` value
+    pub const fn push_bytes(self, value: &[u8]) -> Self {
+        EncodePacked {
+            keccak: self.keccak.update(value),
+        }
+    }
+
+    /// Appends a `string`
+    pub const fn push_string(self, value: &str) -> Self {
+        self.push_bytes(value.as_bytes())
+    }
+
+    /// Appends an `address[]` or `address[k]`, each element padded to 32
+    /// bytes
+    pub const fn push_address_array(mut self, elements: &[Address]) -> Self {
+        let mut i = 0;
+        while i < elements.len() {
+            self = self.push_word(&address_word(&elements[i]));
+            i += 1;
+        }
+        self
+    }
+
+    /// Appends a `uintN[]` or `uintN[k]` where `N` is `BITS`, each element
+    /// padded to 32 bytes
+    pub const fn push_uint_array<const BITS: usize>(mut self, elements: &[u128]) -> Self {
+        let mut i = 0;
+        while i < elements.len() {
+            self = self.push_word(&uint_word::<BITS>(elements[i]));
+            i += 1;
+        }
+        self
+    }
+
+    /// Appends an `intN[]` or `intN[k]` where `N` is `BITS`, each element
+    /// sign-extended to 32 bytes
+    pub const fn push_int_array<const BITS: usize>(mut self, elements: &[i128]) -> Self {
+        let mut i = 0;
+        while i < elements.len() {
+            self = self.push_word(&int_word::<BITS>(elements[i]));
+            i += 1;
+        }
+        self
+    }
+
+    /// Appends a `bool[]` or `bool[k]`, each element padded to 32 bytes
+    pub const fn push_bool_array(mut self, elements: &[bool]) -> Self {
+        let mut i = 0;
+        while i < elements.len() {
+            self = self.push_word(&uint_word::<8>(elements[i] as u128));
+            i += 1;
+        }
+        self
+    }
+
+    /// Appends a `bytesN[]` or `bytesN[k]`, each element right-padded to 32
+    /// bytes
+    pub const fn push_bytes_n_array(mut self, elements: &[&[u8]]) -> Self {
+        let mut i = 0;
+        while i < elements.len() {
+            self = self.push_word(&bytes_n_word(elements[i]));
+            i += 1;
+        }
+        self
+    }
+
+    /// Appends an array of 32-byte elements such as `uint256[]` or
+    /// `bytes32[]`
+    pub const fn push_word_array(mut self, elements: &[[u8; 32]]) -> Self {
+        let mut i = 0;
+        while i < elements.len() {
+            self = self.push_word(&elements[i]);
+            i += 1;
+        }
+        self
+    }
+
+    /// Returns the Keccak-256 hash of the encoding
+    pub const fn finalize(self) -> [u8; 32] {
+        self.keccak.finalize()
+    }
+}
+
+impl Default for EncodePacked {
+    fn default() -> Self {
+        EncodePacked::new()
+    }
+}
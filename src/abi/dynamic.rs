@@ -0,0 +1,573 @@
+//! Runtime encoding and decoding of dynamic types
+
+use crate::address::Address;
+use crate::sol_type::I256;
+use crate::sol_type::U256;
+use crate::solidity::error_selector;
+use alloc::boxed::Box;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt;
+
+/// Longest fixed-size array accepted by [`Type::parse`]
+const MAX_ARRAY_LEN: usize = 1 << 16;
+
+/// A Solidity ABI type
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Type {
+    /// `uintN` with the width in bits
+    Uint(usize),
+    /// `intN` with the width in bits
+    Int(usize),
+    /// `address`
+    Address,
+    /// `bool`
+    Bool,
+    /// `bytesN` with the length in bytes
+    FixedBytes(usize),
+    /// `bytes`
+    Bytes,
+    /// `string`
+    String,
+    /// `T[]`
+    Array(Box<Type>),
+    /// `T[k]`
+    FixedArray(Box<Type>, usize),
+    /// `(T1,T2,...)`
+    Tuple(Vec<Type>),
+}
+
+impl Type {
+    /// Parses a canonical type such as `(address,uint256)[]`
+    ///
+    /// Returns `None` for invalid or non-canonical types, and for the empty
+    /// tuple `()`, which Solidity has no values of.
+    pub fn parse(s: &str) -> Option<Type> {
+        match parse_type(s.as_bytes(), 0) {
+            Some((ty, end)) if end == s.len() => Some(ty),
+            _ => None,
+        }
+    }
+
+    /// Returns whether the encoding of the type has a dynamic length
+    pub fn is_dynamic(&self) -> bool {
+        match self {
+            Type::Bytes | Type::String | Type::Array(_) => true,
+            Type::FixedArray(ty, _) => ty.is_dynamic(),
+            Type::Tuple(types) => types.iter().any(Type::is_dynamic),
+            _ => false,
+        }
+    }
+
+    /// Length of the encoding in the head of the enclosing tuple
+    fn head_len(&self) -> usize {
+        match self {
+            _ if self.is_dynamic() => 32,
+            Type::FixedArray(ty, len) => len.saturating_mul(ty.head_len()),
+            Type::Tuple(types) => types
+                .iter()
+                .fold(0, |len, ty| len.saturating_add(ty.head_len())),
+            _ => 32,
+        }
+    }
+}
+
+impl fmt::Display for Type {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Type::Uint(bits) => write!(f, "uint{bits}"),
+            Type::Int(bits) => write!(f, "int{bits}"),
+            Type::Address => f.write_str("address"),
+            Type::Bool => f.write_str("bool"),
+            Type::FixedBytes(len) => write!(f, "bytes{len}"),
+            Type::Bytes => f.write_str("bytes"),
+            Type::String => f.write_str("string"),
+            Type::Array(ty) => write!(f, "{ty}[]"),
+            Type::FixedArray(ty, len) => write!(f, "{ty}[{len}]"),
+            Type::Tuple(types) => {
+                f.write_str("(")?;
+                for (i, ty) in types.iter().enumerate() {
+                    if i > 0 {
+                        f.write_str(",")?;
+                    }
+                    write!(f, "{ty}")?;
+                }
+                f.write_str(")")
+            }
+        }
+    }
+}
+
+/// Parses a decimal number without leading zeros
+fn parse_number(s: &[u8]) -> Option<usize> {
+    if s.is_empty() || (s[0] == b'0' && s.len() > 1) || !s.iter().all(u8::is_ascii_digit) {
+        return None;
+    }
+    core::str::from_utf8(s).ok()?.parse().ok()
+}
+
+/// Parses the type starting at `i`, returning it and the index after it
+fn parse_type(s: &[u8], i: usize) -> Option<(Type, usize)> {
+    let (mut ty, mut i) = if s.get(i) == Some(&b'(') {
+        // Solidity has no empty tuples
+        let mut types = Vec::new();
+        let mut i = i + 1;
+        loop {
+            let (ty, end) = parse_type(s, i)?;
+            types.push(ty);
+            i = end;
+            match s.get(i) {
+                Some(b',') => i += 1,
+                Some(b')') => break,
+                _ => return None,
+            }
+        }
+        (Type::Tuple(types), i + 1)
+    } else {
+        let end = i + s[i..]
+            .iter()
+            .take_while(|c| c.is_ascii_alphanumeric())
+            .count();
+        let name = &s[i..end];
+        let sized = |prefix: &[u8], min: usize, max: usize, step: usize| {
+            let size = parse_number(name.strip_prefix(prefix)?)?;
+            (min <= size && size <= max && size % step == 0).then_some(size)
+        };
+        let ty = match name {
+            b"address" => Type::Address,
+            b"bool" => Type::Bool,
+            b"bytes" => Type::Bytes,
+            b"string" => Type::String,
+            _ => {
+                if let Some(bits) = sized(b"uint", 8, 256, 8) {
+                    Type::Uint(bits)
+                } else if let Some(bits) = sized(b"int", 8, 256, 8) {
+                    Type::Int(bits)
+                } else {
+                    Type::FixedBytes(sized(b"bytes", 1, 32, 1)?)
+                }
+            }
+        };
+        (ty, end)
+    };
+    while s.get(i) == Some(&b'[') {
+        let end = i + 1 + s[i + 1..].iter().position(|&c| c == b']')?;
+        ty = if end == i + 1 {
+            Type::Array(Box::new(ty))
+        } else {
+            let len = parse_number(&s[i + 1..end])?;
+            if len == 0 || len > MAX_ARRAY_LEN {
+                return None;
+            }
+            Type::FixedArray(Box::new(ty), len)
+        };
+        i = end + 1;
+    }
+    Some((ty, i))
+}
+
+/// A Solidity ABI value
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Value {
+    /// A `uintN` value
+    Uint(U256),
+    /// An `intN` value
+    Int(I256),
+    /// An `address` value
+    Address(Address),
+    /// A `bool` value
+    Bool(bool),
+    /// A `bytesN` value
+    FixedBytes(Vec<u8>),
+    /// A `bytes` value
+    Bytes(Vec<u8>),
+    /// A `string` value
+    String(String),
+    /// A `T[]` value
+    Array(Vec<Value>),
+    /// A `T[k]` value
+    FixedArray(Vec<Value>),
+    /// A tuple value
+    Tuple(Vec<Value>),
+}
+
+impl Value {
+    /// Returns whether the encoding of the value has a dynamic length
+    fn is_dynamic(&self) -> bool {
+        match self {
+            Value::Bytes(_) | Value::String(_) | Value::Array(_) => true,
+            Value::FixedArray(values) | Value::Tuple(values) => {
+                values.iter().any(Value::is_dynamic)
+            }
+            _ => false,
+        }
+    }
+
+    /// Length of the encoding in the head of the enclosing tuple
+    fn head_len(&self) -> usize {
+        match self {
+            _ if self.is_dynamic() => 32,
+            Value::FixedArray(values) | Value::Tuple(values) => {
+                values.iter().map(Value::head_len).sum()
+            }
+            _ => 32,
+        }
+    }
+
+    /// Appends the encoding of a static value, or the tail of a dynamic one
+    fn encode_into(&self, out: &mut Vec<u8>) {
+        match self {
+            Value::Uint(value) => out.extend_from_slice(&value.0),
+            Value::Int(value) => out.extend_from_slice(&value.0),
+            Value::Address(address) => {
+                out.extend_from_slice(&[0; 12]);
+                out.extend_from_slice(&address.0);
+            }
+            Value::Bool(value) => {
+                out.extend_from_slice(&[0; 31]);
+                out.push(*value as u8);
+            }
+            Value::FixedBytes(value) => {
+                assert!(
+                    !value.is_empty() && value.len() <= 32,
+                    "bytesN must be 1 to 32 bytes long"
+                );
+                out.extend_from_slice(value);
+                out.resize(out.len() + 32 - value.len(), 0);
+            }
+            Value::Bytes(value) => encode_bytes(value, out),
+            Value::String(value) => encode_bytes(value.as_bytes(), out),
+            Value::Array(values) => {
+                out.extend_from_slice(&length_word(values.len()));
+                encode_sequence(values, out);
+            }
+            Value::FixedArray(values) | Value::Tuple(values) => encode_sequence(values, out),
+        }
+    }
+}
+
+/// The 32-byte big-endian word of a length or offset
+fn length_word(value: usize) -> [u8; 32] {
+    let mut word = [0; 32];
+    word[24..].copy_from_slice(&(value as u64).to_be_bytes());
+    word
+}
+
+fn encode_bytes(value: &[u8], out: &mut Vec<u8>) {
+    out.extend_from_slice(&length_word(value.len()));
+    out.extend_from_slice(value);
+    out.resize(
+        out.len() + value.len().next_multiple_of(32) - value.len(),
+        0,
+    );
+}
+
+/// Appends the heads of `values` followed by the tails of the dynamic ones
+fn encode_sequence(values: &[Value], out: &mut Vec<u8>) {
+    let start = out.len();
+    let mut tail_offset: usize = values.iter().map(Value::head_len).sum();
+    let mut tails = Vec::new();
+    for value in values {
+        if value.is_dynamic() {
+            out.extend_from_slice(&length_word(tail_offset));
+            let tail_start = tails.len();
+            value.encode_into(&mut tails);
+            tail_offset += tails.len() - tail_start;
+        } else {
+            value.encode_into(out);
+        }
+    }
+    out.extend_from_slice(&tails);
+    debug_assert_eq!(out.len() - start, tail_offset);
+}
+
+/// Encodes `values` as `abi.encode(...)`
+pub fn encode(values: &[Value]) -> Vec<u8> {
+    let mut out = Vec::new();
+    encode_sequence(values, &mut out);
+    out
+}
+
+/// Encodes `values` prefixed with `selector`, as
+/// `abi.encodeWithSelector(selector, ...)`
+pub fn encode_with_selector(selector: [u8; 4], values: &[Value]) -> Vec<u8> {
+    let mut out = selector.to_vec();
+    encode_sequence(values, &mut out);
+    out
+}
+
+/// The reason decoding failed
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DecodeError {
+    /// The data ends before the value
+    UnexpectedEnd,
+    /// An offset or length points outside the data
+    OutOfBounds,
+    /// A value has nonzero padding or a `bool` is neither 0 nor 1
+    InvalidPadding,
+    /// A `string` is not valid UTF-8
+    InvalidUtf8,
+    /// Offsets point to shared tails, so the values would hold more words
+    /// than the data
+    Overlapping,
+    /// A custom error signature is not canonical or has unsupported types
+    InvalidSignature,
+}
+
+impl fmt::Display for DecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            DecodeError::UnexpectedEnd => "unexpected end of data",
+            DecodeError::OutOfBounds => "offset or length out of bounds",
+            DecodeError::InvalidPadding => "invalid padding",
+            DecodeError::InvalidUtf8 => "string is not valid UTF-8",
+            DecodeError::Overlapping => "overlapping values",
+            DecodeError::InvalidSignature => "invalid error signature",
+        })
+    }
+}
+
+impl core::error::Error for DecodeError {}
+
+/// Charges `words` decoded words to `budget`
+///
+/// The budget starts at the number of words of the data, which no encoding
+/// without shared tails exceeds, as each of its words is decoded at most
+/// once. Offsets pointing to the same tail could otherwise make the decoded
+/// values grow exponentially with the nesting of the types.
+fn charge(budget: &mut usize, words: usize) -> Result<(), DecodeError> {
+    match budget.checked_sub(words) {
+        Some(rest) => {
+            *budget = rest;
+            Ok(())
+        }
+        None => Err(DecodeError::Overlapping),
+    }
+}
+
+/// Reads the word at `pos`
+fn read_word(data: &[u8], pos: usize, budget: &mut usize) -> Result<[u8; 32], DecodeError> {
+    match data.get(pos..).and_then(|rest| rest.first_chunk()) {
+        Some(word) => {
+            charge(budget, 1)?;
+            Ok(*word)
+        }
+        None => Err(DecodeError::UnexpectedEnd),
+    }
+}
+
+/// Reads an offset or length that must not exceed `max`
+fn read_usize(
+    data: &[u8],
+    pos: usize,
+    max: usize,
+    budget: &mut usize,
+) -> Result<usize, DecodeError> {
+    let word = read_word(data, pos, budget)?;
+    if word[..24].iter().any(|&b| b != 0) {
+        return Err(DecodeError::OutOfBounds);
+    }
+    match usize::try_from(u64::from_be_bytes(word[24..].try_into().unwrap())) {
+        Ok(value) if value <= max => Ok(value),
+        _ => Err(DecodeError::OutOfBounds),
+    }
+}
+
+/// Checks that every byte of `padding` equals `fill`
+fn check_padding(padding: &[u8], fill: u8) -> Result<(), DecodeError> {
+    if padding.iter().all(|&b| b == fill) {
+        Ok(())
+    } else {
+        Err(DecodeError::InvalidPadding)
+    }
+}
+
+/// Decodes a value of type `ty` whose head is at `pos` of `data`, the
+/// encoding of the enclosing tuple
+fn decode_value(
+    ty: &Type,
+    data: &[u8],
+    pos: usize,
+    budget: &mut usize,
+) -> Result<Value, DecodeError> {
+    if ty.is_dynamic() {
+        let offset = read_usize(data, pos, data.len(), budget)?;
+        return decode_tail(ty, &data[offset..], budget);
+    }
+    match ty {
+        Type::Uint(bits) => {
+            let word = read_word(data, pos, budget)?;
+            check_padding(&word[..32 - bits / 8], 0)?;
+            Ok(Value::Uint(U256(word)))
+        }
+        Type::Int(bits) => {
+            let word = read_word(data, pos, budget)?;
+            let sign = if word[32 - bits / 8] & 0x80 == 0 {
+                0
+            } else {
+                0xff
+            };
+            check_padding(&word[..32 - bits / 8], sign)?;
+            Ok(Value::Int(I256(word)))
+        }
+        Type::Address => {
+            let word = read_word(data, pos, budget)?;
+            check_padding(&word[..12], 0)?;
+            Ok(Value::Address(Address(word[12..].try_into().unwrap())))
+        }
+        Type::Bool => {
+            let word = read_word(data, pos, budget)?;
+            check_padding(&word[..31], 0)?;
+            match word[31] {
+                0 => Ok(Value::Bool(false)),
+                1 => Ok(Value::Bool(true)),
+                _ => Err(DecodeError::InvalidPadding),
+            }
+        }
+        Type::FixedBytes(len) => {
+            let word = read_word(data, pos, budget)?;
+            check_padding(&word[*len..], 0)?;
+            Ok(Value::FixedBytes(word[..*len].to_vec()))
+        }
+        Type::FixedArray(ty, len) => {
+            let data = data.get(pos..).unwrap_or(&[]);
+            let values = decode_sequence(data, *len, |_| ty, budget)?;
+            Ok(Value::FixedArray(values))
+        }
+        Type::Tuple(types) => {
+            let data = data.get(pos..).unwrap_or(&[]);
+            let values = decode_sequence(data, types.len(), |i| &types[i], budget)?;
+            Ok(Value::Tuple(values))
+        }
+        Type::Bytes | Type::String | Type::Array(_) => unreachable!(),
+    }
+}
+
+/// Decodes a dynamic value of type `ty` at the start of `data`
+fn decode_tail(ty: &Type, data: &[u8], budget: &mut usize) -> Result<Value, DecodeError> {
+    match ty {
+        Type::Bytes | Type::String => {
+            let len = read_usize(data, 0, data.len().saturating_sub(32), budget)?;
+            let padded_len = len.next_multiple_of(32);
+            let Some(padded) = data.get(32..32 + padded_len) else {
+                return Err(DecodeError::UnexpectedEnd);
+            };
+            charge(budget, padded_len / 32)?;
+            let (value, padding) = padded.split_at(len);
+            check_padding(padding, 0)?;
+            if *ty == Type::Bytes {
+                Ok(Value::Bytes(value.to_vec()))
+            } else {
+                match core::str::from_utf8(value) {
+                    Ok(value) => Ok(Value::String(value.into())),
+                    Err(_) => Err(DecodeError::InvalidUtf8),
+                }
+            }
+        }
+        Type::Array(ty) => {
+            // every element occupies at least one word, which bounds the
+            // allocation by the length of the data
+            let len = read_usize(data, 0, data.len().saturating_sub(32) / 32, budget)?;
+            let values = decode_sequence(&data[32..], len, |_| ty, budget)?;
+            Ok(Value::Array(values))
+        }
+        Type::FixedArray(ty, len) => {
+            let values = decode_sequence(data, *len, |_| ty, budget)?;
+            Ok(Value::FixedArray(values))
+        }
+        Type::Tuple(types) => {
+            let values = decode_sequence(data, types.len(), |i| &types[i], budget)?;
+            Ok(Value::Tuple(values))
+        }
+        _ => unreachable!(),
+    }
+}
+
+/// Decodes `len` values whose types are given by `type_at`, encoded as a
+/// tuple at the start of `data`
+fn decode_sequence<'a>(
+    data: &[u8],
+    len: usize,
+    type_at: impl Fn(usize) -> &'a Type,
+    budget: &mut usize,
+) -> Result<Vec<Value>, DecodeError> {
+    let mut values = Vec::with_capacity(len.min(data.len() / 32));
+    let mut pos = 0;
+    for i in 0..len {
+        let ty = type_at(i);
+        values.push(decode_value(ty, data, pos, budget)?);
+        pos += ty.head_len();
+    }
+    Ok(values)
+}
+
+/// Decodes `abi.decode(data, (T1, T2, ...))` for the given `types`
+///
+/// Padding must be zero, or the sign extension for `intN`, and offsets and
+/// lengths must lie within `data`, and values must not share their tails.
+/// Data after the encoding is ignored.
+pub fn decode(types: &[Type], data: &[u8]) -> Result<Vec<Value>, DecodeError> {
+    let mut budget = data.len() / 32;
+    decode_sequence(data, types.len(), |i| &types[i], &mut budget)
+}
+
+/// Parses the parameter types of a canonical signature
+fn parse_signature(signature: &str) -> Option<Vec<Type>> {
+    let (name, params) = signature.split_at(signature.find('(')?);
+    let valid_name = !name.is_empty()
+        && name.bytes().enumerate().all(|(i, c)| {
+            c.is_ascii_alphabetic() || c == b'_' || c == b'$' || (i > 0 && c.is_ascii_digit())
+        });
+    match Type::parse(params) {
+        _ if !valid_name => None,
+        _ if params == "()" => Some(Vec::new()),
+        Some(Type::Tuple(types)) => Some(types),
+        _ => None,
+    }
+}
+
+/// Decoded revert data
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Revert {
+    /// `Error(string)` raised by `require` and `revert` with a reason
+    Error(String),
+    /// `Panic(uint256)` raised by failed assertions and arithmetic errors
+    Panic(U256),
+    /// A custom error, the index of its signature and its arguments
+    Custom(usize, Vec<Value>),
+    /// Revert data not matching any known error, including empty data
+    Unknown(Vec<u8>),
+}
+
+/// Decodes revert data as `Error(string)`, `Panic(uint256)` or one of the
+/// custom errors with the canonical `signatures`
+///
+/// A signature that is not canonical or cannot be parsed, such as one with
+/// `function` or `fixed` parameters, gives [`DecodeError::InvalidSignature`].
+pub fn decode_revert(data: &[u8], signatures: &[&str]) -> Result<Revert, DecodeError> {
+    const ERROR: [u8; 4] = error_selector("Error(string)");
+    const PANIC: [u8; 4] = error_selector("Panic(uint256)");
+
+    let Some((selector, args)) = data.split_first_chunk::<4>() else {
+        return Ok(Revert::Unknown(data.to_vec()));
+    };
+    if *selector == ERROR {
+        return match decode(&[Type::String], args)?.pop() {
+            Some(Value::String(reason)) => Ok(Revert::Error(reason)),
+            _ => unreachable!(),
+        };
+    }
+    if *selector == PANIC {
+        return match decode(&[Type::Uint(256)], args)?.pop() {
+            Some(Value::Uint(code)) => Ok(Revert::Panic(code)),
+            _ => unreachable!(),
+        };
+    }
+    for (i, signature) in signatures.iter().enumerate() {
+        let types = parse_signature(signature).ok_or(DecodeError::InvalidSignature)?;
+        if *selector == error_selector(signature) {
+            return Ok(Revert::Custom(i, decode(&types, args)?));
+        }
+    }
+    Ok(Revert::Unknown(data.to_vec()))
+}
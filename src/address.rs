@@ -0,0 +1,340 @@
+//! Ethereum addresses
+//!
+//! Contract addresses of deployments can be predicted in constant
+//! expressions.
+//!
+//! # Examples
+//!
+//! ```rust
+//! # use keccak_const::address::{self, Address};
+//! # use keccak_const::Keccak256;
+//! const FACTORY: Address = Address([0x11; 20]);
+//! const IMPLEMENTATION: Address = Address([0x22; 20]);
+//!
+//! const CLONE: Address = address::create2_address(
+//!     &FACTORY,
+//!     &[0; 32],
+//!     &Keccak256::new()
+//!         .update(&address::minimal_proxy_init_code(&IMPLEMENTATION))
+//!         .finalize(),
+//! );
+//! ```
+//!
+//! With the `std` feature, `SaltMiner` searches for `CREATE2` salts giving
+//! addresses that match a `Pattern`.
+
+#[cfg(feature = "std")]
+mod miner;
+
+use crate::sol_type::Decimal;
+use crate::Keccak256;
+#[cfg(feature = "std")]
+pub use miner::Pattern;
+#[cfg(feature = "std")]
+pub use miner::Progress;
+#[cfg(feature = "std")]
+pub use miner::SaltMiner;
+
+/// A 20-byte Ethereum address
+///
+/// Addresses are displayed as EIP-55 checksummed hex.
+///
+/// # Examples
+///
+/// ```rust
+/// # use keccak_const::address::Address;
+/// const WETH: Address = Address::from_hex("0xC02aaA39b223FE8D0A0e5C4F27eAD9083C756Cc2");
+///
+/// assert_eq!(
+///     b"0xC02aaA39b223FE8D0A0e5C4F27eAD9083C756Cc2",
+///     &WETH.to_checksum_hex()
+/// );
+/// ```
+///
+/// ```compile_fail
+/// # use keccak_const::address::Address;
+/// const WETH: Address = Address::from_hex("0xc02aaA39b223FE8D0A0e5C4F27eAD9083C756Cc2");
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Address(pub [u8; 20]);
+
+impl Address {
+    /// Parses `0x` followed by 40 hex digits, validating the EIP-55 checksum
+    ///
+    /// Addresses in a single case have no checksum and are accepted as they
+    /// are.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the address is malformed or mixed-case with a wrong checksum.
+    pub const fn from_hex(hex: &str) -> Address {
+        parse_hex(hex, None)
+    }
+
+    /// Parses `0x` followed by 40 hex digits, validating the EIP-1191
+    /// checksum of the chain
+    ///
+    /// # Panics
+    ///
+    /// Panics if the address is malformed or mixed-case with a wrong checksum.
+    pub const fn from_hex_with_chain_id(hex: &str, chain_id: u64) -> Address {
+        parse_hex(hex, Some(chain_id))
+    }
+
+    /// Formats the address as EIP-55 checksummed hex
+    pub const fn to_checksum_hex(&self) -> [u8; 42] {
+        checksum_hex(self, None)
+    }
+
+    /// Formats the address as EIP-1191 checksummed hex for the chain
+    ///
+    /// The checksum differs from EIP-55 and is only recognized on chains that
+    /// adopted EIP-1191.
+    pub const fn to_checksum_hex_with_chain_id(&self, chain_id: u64) -> [u8; 42] {
+        checksum_hex(self, Some(chain_id))
+    }
+}
+
+impl core::fmt::Display for Address {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let hex = self.to_checksum_hex();
+        f.write_str(core::str::from_utf8(&hex).unwrap())
+    }
+}
+
+const HEX_DIGITS: &[u8; 16] = b"0123456789abcdef";
+
+/// Formats an address as hex, with the letters selected by the checksum in
+/// uppercase
+const fn checksum_hex(address: &Address, chain_id: Option<u64>) -> [u8; 42] {
+    let mut hex = [0; 42];
+    hex[0] = b'0';
+    hex[1] = b'x';
+    let mut i = 0;
+    while i < 20 {
+        hex[2 + 2 * i] = HEX_DIGITS[(address.0[i] >> 4) as usize];
+        hex[3 + 2 * i] = HEX_DIGITS[(address.0[i] & 0x0f) as usize];
+        i += 1;
+    }
+
+    // EIP-1191 prefixes the hashed hex with the decimal chain id
+    let mut keccak = Keccak256::new();
+    if let Some(chain_id) = chain_id {
+        keccak = keccak
+            .update(Decimal::new(chain_id).as_bytes())
+            .update(b"0x");
+    }
+    let hash = keccak.update(hex.split_at(2).1).finalize();
+
+    let mut i = 0;
+    while i < 40 {
+        let nibble = if i % 2 == 0 {
+            hash[i / 2] >> 4
+        } else {
+            hash[i / 2] & 0x0f
+        };
+        if nibble >= 8 {
+            hex[2 + i] = hex[2 + i].to_ascii_uppercase();
+        }
+        i += 1;
+    }
+    hex
+}
+
+const fn parse_hex(hex: &str, chain_id: Option<u64>) -> Address {
+    let hex = hex.as_bytes();
+    assert!(
+        hex.len() == 42 && hex[0] == b'0' && hex[1] == b'x',
+        "address must be 0x followed by 40 hex digits"
+    );
+    let mut address = [0; 20];
+    let mut lower = false;
+    let mut upper = false;
+    let mut i = 0;
+    while i < 40 {
+        let c = hex[2 + i];
+        let nibble = match c {
+            b'0'..=b'9' => c - b'0',
+            b'a'..=b'f' => c - b'a' + 10,
+            b'A'..=b'F' => c - b'A' + 10,
+            _ => panic!("address must be 0x followed by 40 hex digits"),
+        };
+        lower |= c.is_ascii_lowercase();
+        upper |= c.is_ascii_uppercase();
+        address[i / 2] |= if i % 2 == 0 { nibble << 4 } else { nibble };
+        i += 1;
+    }
+    let address = Address(address);
+
+    if lower && upper {
+        let checksum = checksum_hex(&address, chain_id);
+        let mut i = 2;
+        while i < 42 {
+            assert!(checksum[i] == hex[i], "invalid address checksum");
+            i += 1;
+        }
+    }
+    address
+}
+
+/// Takes the address from the last 20 bytes of a hash
+const fn from_hash(hash: &[u8; 32]) -> Address {
+    Address(*hash.split_last_chunk().unwrap().1)
+}
+
+/// Computes the address of an account from its uncompressed secp256k1 public
+/// key, the big-endian coordinates `x || y`
+///
+/// # Examples
+///
+/// ```rust
+/// # use keccak_const::address::{self, Address};
+/// const OPERATOR: Address = Address::from_hex("0x7E5F4552091A69125d5DfCb7b8C2659029395Bdf");
+/// const OPERATOR_PUBKEY: [u8; 64] = [
+///     0x79, 0xbe, 0x66, 0x7e, 0xf9, 0xdc, 0xbb, 0xac, 0x55, 0xa0, 0x62, 0x95, 0xce, 0x87, 0x0b,
+///     0x07, 0x02, 0x9b, 0xfc, 0xdb, 0x2d, 0xce, 0x28, 0xd9, 0x59, 0xf2, 0x81, 0x5b, 0x16, 0xf8,
+///     0x17, 0x98, 0x48, 0x3a, 0xda, 0x77, 0x26, 0xa3, 0xc4, 0x65, 0x5d, 0xa4, 0xfb, 0xfc, 0x0e,
+///     0x11, 0x08, 0xa8, 0xfd, 0x17, 0xb4, 0x48, 0xa6, 0x85, 0x54, 0x19, 0x9c, 0x47, 0xd0, 0x8f,
+///     0xfb, 0x10, 0xd4, 0xb8,
+/// ];
+///
+/// const _: () = assert!(address::pubkey_matches(&OPERATOR, &OPERATOR_PUBKEY));
+/// ```
+pub const fn address_from_pubkey(pubkey: &[u8; 64]) -> Address {
+    from_hash(&node_id(pubkey))
+}
+
+/// Computes the address of an account from its uncompressed secp256k1 public
+/// key in SEC 1 form, `0x04 || x || y`
+pub const fn address_from_uncompressed_pubkey(pubkey: &[u8; 65]) -> Address {
+    let (prefix, pubkey) = pubkey.split_first_chunk::<1>().unwrap();
+    assert!(
+        prefix[0] == 0x04,
+        "uncompressed public key must start with 0x04"
+    );
+    address_from_pubkey(pubkey.first_chunk().unwrap())
+}
+
+/// Returns whether the address is that of the uncompressed secp256k1 public
+/// key `x || y`
+pub const fn pubkey_matches(address: &Address, pubkey: &[u8; 64]) -> bool {
+    let other = address_from_pubkey(pubkey);
+    let mut i = 0;
+    while i < 20 {
+        if address.0[i] != other.0[i] {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+/// Computes the devp2p node ID of an uncompressed secp256k1 public key
+/// `x || y`, its Keccak-256 hash
+///
+/// The address of the key is the last 20 bytes of the node ID.
+pub const fn node_id(pubkey: &[u8; 64]) -> [u8; 32] {
+    Keccak256::new().update(pubkey).finalize()
+}
+
+/// Computes the address of a contract created by `deployer` with `CREATE`
+///
+/// The address is the hash of the RLP encoding of the deployer and its nonce,
+/// the number of transactions sent by an account or of contracts created by a
+/// contract.
+pub const fn create_address(deployer: &Address, nonce: u64) -> Address {
+    // RLP list header, deployer string header, deployer, nonce header, nonce
+    let mut rlp = [0; 31];
+    let nonce_bytes = nonce.to_be_bytes();
+    let nonce_len = 8 - nonce.leading_zeros() as usize / 8;
+    let mut len = 22;
+    if nonce == 0 {
+        rlp[len] = 0x80;
+        len += 1;
+    } else if nonce < 0x80 {
+        rlp[len] = nonce as u8;
+        len += 1;
+    } else {
+        rlp[len] = 0x80 + nonce_len as u8;
+        len += 1;
+        let mut i = 8 - nonce_len;
+        while i < 8 {
+            rlp[len] = nonce_bytes[i];
+            len += 1;
+            i += 1;
+        }
+    }
+    rlp[0] = 0xc0 + (len - 1) as u8;
+    rlp[1] = 0x80 + 20;
+    let mut i = 0;
+    while i < 20 {
+        rlp[2 + i] = deployer.0[i];
+        i += 1;
+    }
+    from_hash(&Keccak256::new().update(rlp.split_at(len).0).finalize())
+}
+
+/// Computes the address of a contract created by `deployer` with `CREATE2`,
+/// as specified by EIP-1014
+pub const fn create2_address(
+    deployer: &Address,
+    salt: &[u8; 32],
+    init_code_hash: &[u8; 32],
+) -> Address {
+    from_hash(
+        &Keccak256::new()
+            .update(&[0xff])
+            .update(&deployer.0)
+            .update(salt)
+            .update(init_code_hash)
+            .finalize(),
+    )
+}
+
+/// Init code of the CREATE3 proxy, which deploys its calldata as init code
+/// with `CREATE`
+pub const CREATE3_PROXY_INIT_CODE: [u8; 16] = [
+    0x67, 0x36, 0x3d, 0x3d, 0x37, 0x36, 0x3d, 0x34, 0xf0, 0x3d, 0x52, 0x60, 0x08, 0x60, 0x18, 0xf3,
+];
+
+/// Computes the address of a contract created by `deployer` with CREATE3
+///
+/// The deployer creates [`CREATE3_PROXY_INIT_CODE`] with `CREATE2` and the
+/// proxy creates the contract with its first nonce, so the address depends
+/// only on the deployer and the salt. Factories that derive the salt from the
+/// caller, such as `keccak256(abi.encodePacked(msg.sender, salt))`, must be
+/// given the derived salt.
+pub const fn create3_address(deployer: &Address, salt: &[u8; 32]) -> Address {
+    let proxy = create2_address(
+        deployer,
+        salt,
+        &Keccak256::new().update(&CREATE3_PROXY_INIT_CODE).finalize(),
+    );
+    create_address(&proxy, 1)
+}
+
+/// Builds the init code of an ERC-1167 minimal proxy delegating to
+/// `implementation`
+pub const fn minimal_proxy_init_code(implementation: &Address) -> [u8; 55] {
+    const PREFIX: [u8; 20] = [
+        0x3d, 0x60, 0x2d, 0x80, 0x60, 0x0a, 0x3d, 0x39, 0x81, 0xf3, 0x36, 0x3d, 0x3d, 0x37, 0x3d,
+        0x3d, 0x3d, 0x36, 0x3d, 0x73,
+    ];
+    const SUFFIX: [u8; 15] = [
+        0x5a, 0xf4, 0x3d, 0x82, 0x80, 0x3e, 0x90, 0x3d, 0x91, 0x60, 0x2b, 0x57, 0xfd, 0x5b, 0xf3,
+    ];
+
+    let mut init_code = [0; 55];
+    let mut i = 0;
+    while i < 20 {
+        init_code[i] = PREFIX[i];
+        init_code[20 + i] = implementation.0[i];
+        i += 1;
+    }
+    let mut i = 0;
+    while i < 15 {
+        init_code[40 + i] = SUFFIX[i];
+        i += 1;
+    }
+    init_code
+}
@@ -0,0 +1,239 @@
+//! Multithreaded search for `CREATE2` salts of matching addresses
+
+use super::Address;
+use crate::Keccak256;
+use core::sync::atomic::AtomicBool;
+use core::sync::atomic::AtomicU64;
+use core::sync::atomic::Ordering;
+use std::sync::Mutex;
+use std::thread;
+
+/// Offset of the salt counter in the `CREATE2` preimage,
+/// `0xff || deployer || salt || init_code_hash`
+const COUNTER_OFFSET: usize = 1 + 20 + 24;
+
+/// Number of attempts between progress updates and cancellation checks
+const BATCH: u64 = 1024;
+
+/// A pattern of addresses, the bits selected by a mask having given values
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Pattern {
+    mask: [u8; 20],
+    value: [u8; 20],
+}
+
+impl Pattern {
+    /// Constructs a pattern matching the bits of `value` selected by `mask`
+    pub const fn masked(mask: &[u8; 20], value: &[u8; 20]) -> Pattern {
+        let mut masked = [0; 20];
+        let mut i = 0;
+        while i < 20 {
+            masked[i] = value[i] & mask[i];
+            i += 1;
+        }
+        Pattern {
+            mask: *mask,
+            value: masked,
+        }
+    }
+
+    /// Constructs a pattern matching addresses starting with `prefix`
+    pub const fn prefix(prefix: &[u8]) -> Pattern {
+        assert!(prefix.len() <= 20, "pattern is longer than an address");
+        let mut mask = [0; 20];
+        let mut value = [0; 20];
+        let mut i = 0;
+        while i < prefix.len() {
+            mask[i] = 0xff;
+            value[i] = prefix[i];
+            i += 1;
+        }
+        Pattern { mask, value }
+    }
+
+    /// Constructs a pattern matching addresses ending with `suffix`
+    pub const fn suffix(suffix: &[u8]) -> Pattern {
+        assert!(suffix.len() <= 20, "pattern is longer than an address");
+        let start = 20 - suffix.len();
+        let mut mask = [0; 20];
+        let mut value = [0; 20];
+        let mut i = 0;
+        while i < suffix.len() {
+            mask[start + i] = 0xff;
+            value[start + i] = suffix[i];
+            i += 1;
+        }
+        Pattern { mask, value }
+    }
+
+    /// Constructs a pattern matching addresses with `n` leading zero bytes
+    pub const fn leading_zeros(n: usize) -> Pattern {
+        assert!(n <= 20, "pattern is longer than an address");
+        Pattern::prefix([0; 20].split_at(n).0)
+    }
+
+    /// Constructs a pattern matching addresses that match both patterns
+    pub const fn and(&self, other: &Pattern) -> Pattern {
+        let mut pattern = *self;
+        let mut i = 0;
+        while i < 20 {
+            assert!(
+                (self.value[i] ^ other.value[i]) & self.mask[i] & other.mask[i] == 0,
+                "patterns match no address"
+            );
+            pattern.mask[i] |= other.mask[i];
+            pattern.value[i] |= other.value[i];
+            i += 1;
+        }
+        pattern
+    }
+
+    /// Returns whether `address` matches the pattern
+    pub const fn matches(&self, address: &Address) -> bool {
+        let mut i = 0;
+        while i < 20 {
+            if address.0[i] & self.mask[i] != self.value[i] {
+                return false;
+            }
+            i += 1;
+        }
+        true
+    }
+}
+
+/// Progress of a search, shared with the threads observing or cancelling it
+#[derive(Debug, Default)]
+pub struct Progress {
+    attempts: AtomicU64,
+    cancelled: AtomicBool,
+}
+
+impl Progress {
+    /// Constructs the progress of a search that has not started
+    pub const fn new() -> Progress {
+        Progress {
+            attempts: AtomicU64::new(0),
+            cancelled: AtomicBool::new(false),
+        }
+    }
+
+    /// Returns the number of salts tried so far, updated in batches
+    pub fn attempts(&self) -> u64 {
+        self.attempts.load(Ordering::Relaxed)
+    }
+
+    /// Stops the search, which then returns `None` unless a salt was found
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::Relaxed);
+    }
+
+    /// Returns whether the search was cancelled
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::Relaxed)
+    }
+}
+
+/// A search for a salt giving a `CREATE2` address that matches a [`Pattern`]
+///
+/// Salts are the base salt with a counter in their last 8 bytes, big-endian,
+/// so the first bytes can hold the caller address that some factories
+/// require. The preimage fits a single Keccak-256 block, which is absorbed
+/// once; each attempt only writes the counter into the absorbed state.
+///
+/// # Examples
+///
+/// ```rust
+/// # use keccak_const::address::{self, Address, Pattern, Progress, SaltMiner};
+/// let deployer = Address([0x11; 20]);
+/// let miner = SaltMiner::new(&deployer, &[0x22; 32], Pattern::leading_zeros(1));
+///
+/// let (salt, address) = miner.mine(&Progress::new()).unwrap();
+/// assert_eq!(0, address.0[0]);
+/// assert_eq!(address, address::create2_address(&deployer, &salt, &[0x22; 32]));
+/// ```
+#[derive(Clone, Debug)]
+pub struct SaltMiner {
+    deployer: Address,
+    init_code_hash: [u8; 32],
+    pattern: Pattern,
+    salt: [u8; 32],
+    threads: usize,
+}
+
+impl SaltMiner {
+    /// Constructs a search for contracts created by `deployer` from init code
+    /// with the given hash, using all available threads
+    pub fn new(deployer: &Address, init_code_hash: &[u8; 32], pattern: Pattern) -> SaltMiner {
+        SaltMiner {
+            deployer: *deployer,
+            init_code_hash: *init_code_hash,
+            pattern,
+            salt: [0; 32],
+            threads: thread::available_parallelism().map_or(1, |threads| threads.get()),
+        }
+    }
+
+    /// Sets the first 24 bytes of the salts
+    pub fn salt_prefix(mut self, prefix: &[u8; 24]) -> Self {
+        self.salt[..24].copy_from_slice(prefix);
+        self
+    }
+
+    /// Sets the number of threads
+    pub fn threads(mut self, threads: usize) -> Self {
+        assert!(threads > 0, "at least one thread is required");
+        self.threads = threads;
+        self
+    }
+
+    /// Searches for a salt and returns it with the address
+    ///
+    /// Blocks until a salt is found or `progress` is cancelled. The salt found
+    /// first is returned, which with a single thread is the one with the lowest
+    /// counter.
+    pub fn mine(&self, progress: &Progress) -> Option<([u8; 32], Address)> {
+        let template = Keccak256::new()
+            .update(&[0xff])
+            .update(&self.deployer.0)
+            .update(&self.salt)
+            .update(&self.init_code_hash);
+        let found = Mutex::new(None);
+        let done = AtomicBool::new(false);
+        let threads = self.threads as u64;
+
+        thread::scope(|scope| {
+            for first in 0..threads {
+                let (template, found, done) = (&template, &found, &done);
+                scope.spawn(move || {
+                    let mut counter = first;
+                    loop {
+                        for _ in 0..BATCH {
+                            let mut keccak = template.clone();
+                            keccak.state =
+                                keccak.state.xor_at(COUNTER_OFFSET, &counter.to_be_bytes());
+                            let hash = keccak.finalize();
+                            let address = Address(*hash.split_last_chunk().unwrap().1);
+                            if self.pattern.matches(&address) {
+                                let mut salt = self.salt;
+                                salt[24..].copy_from_slice(&counter.to_be_bytes());
+                                done.store(true, Ordering::Relaxed);
+                                found.lock().unwrap().get_or_insert((salt, address));
+                                return;
+                            }
+                            counter = match counter.checked_add(threads) {
+                                Some(counter) => counter,
+                                None => return,
+                            };
+                        }
+                        progress.attempts.fetch_add(BATCH, Ordering::Relaxed);
+                        if done.load(Ordering::Relaxed) || progress.is_cancelled() {
+                            return;
+                        }
+                    }
+                });
+            }
+        });
+
+        found.into_inner().unwrap()
+    }
+}
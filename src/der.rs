@@ -0,0 +1,149 @@
+//! ASN.1 DER encodings of the SHA-3 algorithm identifiers, as assigned by NIST
+//! under `2.16.840.1.101.3.4` and by the PKIX arc `1.3.6.1.5.5.7.6` (RFC 8692).
+
+use crate::Sha3_224;
+use crate::Sha3_256;
+use crate::Sha3_384;
+use crate::Sha3_512;
+use crate::Shake128;
+use crate::Shake256;
+
+macro_rules! digest_info {
+    (
+        $name:ident,
+        $digest_len:literal,
+        oid: [$($oid:literal),* $(,)?],
+        prefix: [$($prefix:literal),* $(,)?],
+        rsa: [$($rsa:literal),* $(,)?],
+        ecdsa: [$($ecdsa:literal),* $(,)?],
+    ) => {
+        impl $name {
+            /// DER encoding (tag, length and value) of the hash algorithm's
+            /// object identifier
+            pub const OID: &'static [u8] = &[$($oid),*];
+
+            /// DER encoding of a `DigestInfo` structure up to, but excluding,
+            /// the digest itself
+            pub const DIGEST_INFO_PREFIX: &'static [u8] = &[$($prefix),*];
+
+            /// Length of the output of [`Self::digest_info`]
+            pub const DIGEST_INFO_LEN: usize = Self::DIGEST_INFO_PREFIX.len() + $digest_len;
+
+            /// DER encoding of the object identifier for RSA signatures with
+            /// this hash function
+            pub const RSA_SIGNATURE_OID: &'static [u8] = &[$($rsa),*];
+
+            /// DER encoding of the object identifier for ECDSA signatures with
+            /// this hash function
+            pub const ECDSA_SIGNATURE_OID: &'static [u8] = &[$($ecdsa),*];
+
+            /// Finalizes the hasher and returns the DER encoded `DigestInfo`
+            ///
+            /// `N` must be equal to [`Self::DIGEST_INFO_LEN`].
+            pub const fn digest_info<const N: usize>(&self) -> [u8; N] {
+                assert!(N == Self::DIGEST_INFO_LEN, "invalid DigestInfo length");
+                let digest: [u8; $digest_len] = self.finalize();
+                let mut output = [0; N];
+                let mut i = 0;
+                while i < Self::DIGEST_INFO_PREFIX.len() {
+                    output[i] = Self::DIGEST_INFO_PREFIX[i];
+                    i += 1;
+                }
+                let mut j = 0;
+                while j < digest.len() {
+                    output[i + j] = digest[j];
+                    j += 1;
+                }
+                output
+            }
+        }
+    };
+}
+
+digest_info!(
+    Sha3_224,
+    28,
+    oid: [0x06, 0x09, 0x60, 0x86, 0x48, 0x01, 0x65, 0x03, 0x04, 0x02, 0x07],
+    prefix: [
+        0x30, 0x2d, 0x30, 0x0d, 0x06, 0x09, 0x60, 0x86, 0x48, 0x01, 0x65, 0x03, 0x04, 0x02, 0x07,
+        0x05, 0x00, 0x04, 0x1c,
+    ],
+    // id-rsassa-pkcs1-v1_5-with-sha3-224
+    rsa: [0x06, 0x09, 0x60, 0x86, 0x48, 0x01, 0x65, 0x03, 0x04, 0x03, 0x0d],
+    // id-ecdsa-with-sha3-224
+    ecdsa: [0x06, 0x09, 0x60, 0x86, 0x48, 0x01, 0x65, 0x03, 0x04, 0x03, 0x09],
+);
+
+digest_info!(
+    Sha3_256,
+    32,
+    oid: [0x06, 0x09, 0x60, 0x86, 0x48, 0x01, 0x65, 0x03, 0x04, 0x02, 0x08],
+    prefix: [
+        0x30, 0x31, 0x30, 0x0d, 0x06, 0x09, 0x60, 0x86, 0x48, 0x01, 0x65, 0x03, 0x04, 0x02, 0x08,
+        0x05, 0x00, 0x04, 0x20,
+    ],
+    // id-rsassa-pkcs1-v1_5-with-sha3-256
+    rsa: [0x06, 0x09, 0x60, 0x86, 0x48, 0x01, 0x65, 0x03, 0x04, 0x03, 0x0e],
+    // id-ecdsa-with-sha3-256
+    ecdsa: [0x06, 0x09, 0x60, 0x86, 0x48, 0x01, 0x65, 0x03, 0x04, 0x03, 0x0a],
+);
+
+digest_info!(
+    Sha3_384,
+    48,
+    oid: [0x06, 0x09, 0x60, 0x86, 0x48, 0x01, 0x65, 0x03, 0x04, 0x02, 0x09],
+    prefix: [
+        0x30, 0x41, 0x30, 0x0d, 0x06, 0x09, 0x60, 0x86, 0x48, 0x01, 0x65, 0x03, 0x04, 0x02, 0x09,
+        0x05, 0x00, 0x04, 0x30,
+    ],
+    // id-rsassa-pkcs1-v1_5-with-sha3-384
+    rsa: [0x06, 0x09, 0x60, 0x86, 0x48, 0x01, 0x65, 0x03, 0x04, 0x03, 0x0f],
+    // id-ecdsa-with-sha3-384
+    ecdsa: [0x06, 0x09, 0x60, 0x86, 0x48, 0x01, 0x65, 0x03, 0x04, 0x03, 0x0b],
+);
+
+digest_info!(
+    Sha3_512,
+    64,
+    oid: [0x06, 0x09, 0x60, 0x86, 0x48, 0x01, 0x65, 0x03, 0x04, 0x02, 0x0a],
+    prefix: [
+        0x30, 0x51, 0x30, 0x0d, 0x06, 0x09, 0x60, 0x86, 0x48, 0x01, 0x65, 0x03, 0x04, 0x02, 0x0a,
+        0x05, 0x00, 0x04, 0x40,
+    ],
+    // id-rsassa-pkcs1-v1_5-with-sha3-512
+    rsa: [0x06, 0x09, 0x60, 0x86, 0x48, 0x01, 0x65, 0x03, 0x04, 0x03, 0x10],
+    // id-ecdsa-with-sha3-512
+    ecdsa: [0x06, 0x09, 0x60, 0x86, 0x48, 0x01, 0x65, 0x03, 0x04, 0x03, 0x0c],
+);
+
+// RFC 8692 fixes the output length of SHAKE128 to 256 bits and requires the
+// algorithm parameters to be absent.
+digest_info!(
+    Shake128,
+    32,
+    oid: [0x06, 0x09, 0x60, 0x86, 0x48, 0x01, 0x65, 0x03, 0x04, 0x02, 0x0b],
+    prefix: [
+        0x30, 0x2f, 0x30, 0x0b, 0x06, 0x09, 0x60, 0x86, 0x48, 0x01, 0x65, 0x03, 0x04, 0x02, 0x0b,
+        0x04, 0x20,
+    ],
+    // id-RSASSA-PSS-SHAKE128
+    rsa: [0x06, 0x08, 0x2b, 0x06, 0x01, 0x05, 0x05, 0x07, 0x06, 0x1e],
+    // id-ecdsa-with-shake128
+    ecdsa: [0x06, 0x08, 0x2b, 0x06, 0x01, 0x05, 0x05, 0x07, 0x06, 0x20],
+);
+
+// RFC 8692 fixes the output length of SHAKE256 to 512 bits and requires the
+// algorithm parameters to be absent.
+digest_info!(
+    Shake256,
+    64,
+    oid: [0x06, 0x09, 0x60, 0x86, 0x48, 0x01, 0x65, 0x03, 0x04, 0x02, 0x0c],
+    prefix: [
+        0x30, 0x4f, 0x30, 0x0b, 0x06, 0x09, 0x60, 0x86, 0x48, 0x01, 0x65, 0x03, 0x04, 0x02, 0x0c,
+        0x04, 0x40,
+    ],
+    // id-RSASSA-PSS-SHAKE256
+    rsa: [0x06, 0x08, 0x2b, 0x06, 0x01, 0x05, 0x05, 0x07, 0x06, 0x1f],
+    // id-ecdsa-with-shake256
+    ecdsa: [0x06, 0x08, 0x2b, 0x06, 0x01, 0x05, 0x05, 0x07, 0x06, 0x21],
+);
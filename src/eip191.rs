@@ -0,0 +1,73 @@
+//! EIP-191 signed data hashing
+//!
+//! Signed data is `0x19 || version || version specific data || data`, hashed
+//! with Keccak-256. Version `0x45` (`E`) is the `personal_sign` and `eth_sign`
+//! message prefix, `0x00` is data with an intended validator and `0x01` is
+//! EIP-712 typed data.
+//!
+//! # Examples
+//!
+//! ```rust
+//! # use keccak_const::eip191;
+//! const HASH: [u8; 32] = eip191::personal_message_hash(b"Hello World");
+//!
+//! assert_eq!(
+//!     [
+//!         0xa1, 0xde, 0x98, 0x86, 0x00, 0xa4, 0x2c, 0x4b, 0x4a, 0xb0, 0x89, 0xb6, 0x19, 0x29,
+//!         0x7c, 0x17, 0xd5, 0x3c, 0xff, 0xae, 0x5d, 0x51, 0x20, 0xd8, 0x2d, 0x8a, 0x92, 0xd0,
+//!         0xbb, 0x3b, 0x78, 0xf2,
+//!     ],
+//!     HASH
+//! );
+//! ```
+//!
+//! Messages that are not available at once can be hashed in parts, given their
+//! total length:
+//!
+//! ```rust
+//! # use keccak_const::eip191;
+//! let hash = eip191::personal_message(11)
+//!     .update(b"Hello")
+//!     .update(b" World")
+//!     .finalize();
+//!
+//! assert_eq!(eip191::personal_message_hash(b"Hello World"), hash);
+//! ```
+
+use crate::address::Address;
+use crate::eip712;
+use crate::sol_type::Decimal;
+use crate::Keccak256;
+
+/// Starts hashing a `personal_sign` message of `len` bytes
+///
+/// The returned hasher has absorbed the `"\x19Ethereum Signed Message:\n"`
+/// prefix and the decimal length; the message is absorbed with
+/// [`Keccak256::update`]. A message of a different length gives a hash that no
+/// wallet signs.
+pub const fn personal_message(len: usize) -> Keccak256 {
+    Keccak256::new()
+        .update(b"\x19Ethereum Signed Message:\n")
+        .update(Decimal::new(len as u64).as_bytes())
+}
+
+/// Computes the hash of a `personal_sign` message, version `0x45`
+pub const fn personal_message_hash(message: &[u8]) -> [u8; 32] {
+    personal_message(message.len()).update(message).finalize()
+}
+
+/// Computes the hash of data with an intended validator, version `0x00`
+pub const fn validator_data_hash(validator: &Address, data: &[u8]) -> [u8; 32] {
+    Keccak256::new()
+        .update(b"\x19\x00")
+        .update(&validator.0)
+        .update(data)
+        .finalize()
+}
+
+/// Computes the hash of EIP-712 typed data, version `0x01`
+///
+/// This is [`eip712::digest`].
+pub const fn typed_data_hash(domain_separator: &[u8; 32], hash_struct: &[u8; 32]) -> [u8; 32] {
+    eip712::digest(domain_separator, hash_struct)
+}
@@ -0,0 +1,788 @@
+//! EIP-712 hashing of typed structured data
+//!
+//! Struct types are given by their definitions such as
+//! `Mail(Person from,Person to,string contents)`. The first definition is the
+//! primary type and the others may be referenced by its members, directly or
+//! transitively; unreferenced definitions are ignored, so all types of a
+//! schema can be passed together.
+//!
+//! Rust structs implement [`Eip712`] with `#[derive(Eip712)]` from the
+//! `derive` feature, which maps field types to member types through
+//! [`Eip712Type`].
+//!
+//! # Examples
+//!
+//! ```rust
+//! # use keccak_const::address::Address;
+//! # use keccak_const::eip712;
+//! # use keccak_const::eip712::HashStruct;
+//! const TYPES: [&str; 2] = [
+//!     "Mail(Person from,Person to,string contents)",
+//!     "Person(string name,address wallet)",
+//! ];
+//! const MAIL_TYPE_HASH: [u8; 32] = eip712::type_hash(&TYPES);
+//! const PERSON_TYPE_HASH: [u8; 32] = eip712::type_hash(&[TYPES[1]]);
+//!
+//! const DOMAIN_SEPARATOR: [u8; 32] = eip712::domain_separator(
+//!     Some("Ether Mail"),
+//!     Some("1"),
+//!     Some(1),
+//!     Some(&Address([0xcc; 20])),
+//!     None,
+//! );
+//!
+//! let from = HashStruct::new(&PERSON_TYPE_HASH)
+//!     .push_string("Cow")
+//!     .push_address(&Address([0xcd; 20]))
+//!     .finalize();
+//! let to = HashStruct::new(&PERSON_TYPE_HASH)
+//!     .push_string("Bob")
+//!     .push_address(&Address([0xbb; 20]))
+//!     .finalize();
+//! let mail = HashStruct::new(&MAIL_TYPE_HASH)
+//!     .push_struct(&from)
+//!     .push_struct(&to)
+//!     .push_string("Hello, Bob!")
+//!     .finalize();
+//! let digest = eip712::digest(&DOMAIN_SEPARATOR, &mail);
+//! ```
+
+use crate::abi::address_word;
+use crate::abi::bytes_n_word;
+use crate::abi::int_word;
+use crate::abi::uint_word;
+use crate::address::Address;
+use crate::sol_type::AbiName;
+use crate::sol_type::Bytes;
+use crate::sol_type::FixedBytes;
+use crate::sol_type::I256;
+use crate::sol_type::U256;
+use crate::solidity::check_type;
+use crate::Keccak256;
+#[cfg(feature = "derive")]
+pub use keccak_const_derive::Eip712;
+
+/// Maximum number of struct definitions
+const MAX_TYPES: usize = 32;
+
+/// Returns the end of the struct name of a definition `Name(...)`
+const fn name_end(definition: &[u8]) -> usize {
+    let mut i = 0;
+    while i < definition.len() && definition[i] != b'(' {
+        i += 1;
+    }
+    assert!(
+        i > 0 && i < definition.len() && definition[definition.len() - 1] == b')',
+        "invalid EIP-712 type definition"
+    );
+    i
+}
+
+/// Compares `a[a_start..a_end]` with `b[b_start..b_end]`
+const fn compare(
+    a: &[u8],
+    a_start: usize,
+    a_end: usize,
+    b: &[u8],
+    b_start: usize,
+    b_end: usize,
+) -> core::cmp::Ordering {
+    let mut i = 0;
+    while a_start + i < a_end && b_start + i < b_end {
+        if a[a_start + i] != b[b_start + i] {
+            return if a[a_start + i] < b[b_start + i] {
+                core::cmp::Ordering::Less
+            } else {
+                core::cmp::Ordering::Greater
+            };
+        }
+        i += 1;
+    }
+    if a_end - a_start < b_end - b_start {
+        core::cmp::Ordering::Less
+    } else if a_end - a_start > b_end - b_start {
+        core::cmp::Ordering::Greater
+    } else {
+        core::cmp::Ordering::Equal
+    }
+}
+
+/// Returns the index of the definition of the struct named `s[start..end]`
+const fn find_type(types: &[&str], s: &[u8], start: usize, end: usize) -> Option<usize> {
+    let mut i = 0;
+    while i < types.len() {
+        let definition = types[i].as_bytes();
+        if compare(definition, 0, name_end(definition), s, start, end).is_eq() {
+            return Some(i);
+        }
+        i += 1;
+    }
+    None
+}
+
+/// Returns whether `s[start..end]`, the base of a valid Solidity type, has no
+/// EIP-712 encoding: a tuple, `function` or a fixed-point number
+const fn is_unsupported_type(s: &[u8], start: usize, end: usize) -> bool {
+    s[start] == b'('
+        || compare(s, start, end, b"function", 0, 8).is_eq()
+        || (end - start > 5 && compare(s, start, start + 5, b"fixed", 0, 5).is_eq())
+        || (end - start > 6 && compare(s, start, start + 6, b"ufixed", 0, 6).is_eq())
+}
+
+/// Returns whether a valid Solidity type is `intN`, and its width, or `None`
+/// if it is not an integer type
+const fn integer_type(s: &[u8]) -> Option<(bool, usize)> {
+    let (signed, mut i) = if s.len() > 4 && s[0] == b'u' {
+        (false, 4)
+    } else if s.len() > 3 && s[0] == b'i' {
+        (true, 3)
+    } else {
+        return None;
+    };
+    if !compare(s, 0, i, b"uint", 4 - i, 4).is_eq() {
+        return None;
+    }
+    let mut bits = 0;
+    while i < s.len() {
+        if !s[i].is_ascii_digit() {
+            return None;
+        }
+        bits = 10 * bits + (s[i] - b'0') as usize;
+        i += 1;
+    }
+    Some((signed, bits))
+}
+
+/// Returns which definitions the primary type depends on, including itself
+const fn dependencies(types: &[&str]) -> [bool; MAX_TYPES] {
+    assert!(
+        !types.is_empty() && types.len() <= MAX_TYPES,
+        "invalid number of EIP-712 type definitions"
+    );
+    let mut referenced = [false; MAX_TYPES];
+    let mut stack = [0; MAX_TYPES];
+    let mut stack_len = 1;
+    referenced[0] = true;
+    while stack_len > 0 {
+        stack_len -= 1;
+        let definition = types[stack[stack_len]].as_bytes();
+        let end = definition.len() - 1;
+        let mut i = name_end(definition) + 1;
+        while i < end {
+            // each member is `type name`, where `type` is a struct name or
+            // an atomic or dynamic type, possibly followed by array suffixes
+            let type_start = i;
+            while i < end && definition[i] != b' ' {
+                i += 1;
+            }
+            let type_end = i;
+            let mut base_end = type_start;
+            while base_end < type_end && definition[base_end] != b'[' {
+                base_end += 1;
+            }
+            let name_start = i + 1;
+            while i < end && definition[i] != b',' {
+                i += 1;
+            }
+            assert!(
+                type_start < type_end && name_start < i,
+                "invalid EIP-712 type definition"
+            );
+            if let Some(t) = find_type(types, definition, type_start, base_end) {
+                if !referenced[t] {
+                    referenced[t] = true;
+                    stack[stack_len] = t;
+                    stack_len += 1;
+                }
+            } else {
+                let member_type = definition.split_at(type_end).0.split_at(type_start).1;
+                assert!(
+                    check_type(member_type, 0) == member_type.len(),
+                    "invalid EIP-712 member type"
+                );
+                assert!(
+                    !is_unsupported_type(definition, type_start, base_end),
+                    "EIP-712 member types cannot be tuples, `function` or fixed-point"
+                );
+            }
+            if i < end {
+                i += 1;
+                assert!(i < end, "invalid EIP-712 type definition");
+            }
+        }
+    }
+    referenced
+}
+
+/// Returns the definitions of `encodeType` in order: the primary type followed
+/// by the referenced struct types sorted by name
+const fn encode_type_order(types: &[&str]) -> ([usize; MAX_TYPES], usize) {
+    let mut remaining = dependencies(types);
+    remaining[0] = false;
+    let mut order = [0; MAX_TYPES];
+    let mut len = 1;
+    loop {
+        let mut next: Option<usize> = None;
+        let mut i = 1;
+        while i < types.len() {
+            if remaining[i] {
+                next = match next {
+                    Some(n) => {
+                        let a = types[i].as_bytes();
+                        let b = types[n].as_bytes();
+                        if compare(a, 0, name_end(a), b, 0, name_end(b)).is_lt() {
+                            Some(i)
+                        } else {
+                            Some(n)
+                        }
+                    }
+                    None => Some(i),
+                };
+            }
+            i += 1;
+        }
+        match next {
+            Some(n) => {
+                remaining[n] = false;
+                order[len] = n;
+                len += 1;
+            }
+            None => return (order, len),
+        }
+    }
+}
+
+/// Computes `typeHash`, the Keccak-256 hash of `encodeType` of the first
+/// definition in `types`
+pub const fn type_hash(types: &[&str]) -> [u8; 32] {
+    let (order, len) = encode_type_order(types);
+    let mut keccak = Keccak256::new();
+    let mut i = 0;
+    while i < len {
+        keccak = keccak.update(types[order[i]].as_bytes());
+        i += 1;
+    }
+    keccak.finalize()
+}
+
+/// The `encodeType` string of the first definition in `types`, stored in a
+/// buffer of `N` bytes
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct EncodeType<const N: usize> {
+    bytes: [u8; N],
+    len: usize,
+}
+
+impl<const N: usize> EncodeType<N> {
+    /// Builds `encodeType` of the first definition in `types`
+    pub const fn new(types: &[&str]) -> Self {
+        let (order, len) = encode_type_order(types);
+        let mut encode_type = EncodeType {
+            bytes: [0; N],
+            len: 0,
+        };
+        let mut i = 0;
+        while i < len {
+            let definition = types[order[i]].as_bytes();
+            assert!(
+                encode_type.len + definition.len() <= N,
+                "encodeType exceeds the buffer"
+            );
+            let mut j = 0;
+            while j < definition.len() {
+                encode_type.bytes[encode_type.len] = definition[j];
+                encode_type.len += 1;
+                j += 1;
+            }
+            i += 1;
+        }
+        encode_type
+    }
+
+    /// Returns the `encodeType` string
+    pub const fn as_bytes(&self) -> &[u8] {
+        self.bytes.split_at(self.len).0
+    }
+
+    /// Returns the `encodeType` string
+    pub const fn as_str(&self) -> &str {
+        match core::str::from_utf8(self.as_bytes()) {
+            Ok(encode_type) => encode_type,
+            Err(_) => unreachable!(),
+        }
+    }
+
+    /// Computes `typeHash`
+    pub const fn type_hash(&self) -> [u8; 32] {
+        Keccak256::new().update(self.as_bytes()).finalize()
+    }
+}
+
+/// `hashStruct(s) = keccak256(typeHash ‖ encodeData(s))`, built by appending
+/// the encoded members in order
+#[derive(Clone)]
+pub struct HashStruct {
+    keccak: Keccak256,
+}
+
+impl HashStruct {
+    /// Starts the hash of a struct with the given `typeHash`
+    pub const fn new(type_hash: &[u8; 32]) -> HashStruct {
+        HashStruct {
+            keccak: Keccak256::new().update(type_hash),
+        }
+    }
+
+    /// Appends an encoded member, a 32-byte value such as a `uint256`,
+    /// `int256` or `bytes32`
+    pub const fn push_word(self, word: &[u8; 32]) -> Self {
+        HashStruct {
+            keccak: self.keccak.update(word),
+        }
+    }
+
+    /// Appends a `uintN` member where `N` is `BITS`
+    pub const fn push_uint<const BITS: usize>(self, value: u128) -> Self {
+        self.push_word(&uint_word::<BITS>(value))
+    }
+
+    /// Appends an `intN` member where `N` is `BITS`
+    pub const fn push_int<const BITS: usize>(self, value: i128) -> Self {
+        self.push_word(&int_word::<BITS>(value))
+    }
+
+    /// Appends an `address` member
+    pub const fn push_address(self, address: &Address) -> Self {
+        self.push_word(&address_word(address))
+    }
+
+    /// Appends a `bool` member
+    pub const fn push_bool(self, value: bool) -> Self {
+        self.push_word(&uint_word::<8>(value as u128))
+    }
+
+    /// Appends a `bytesN` member where `N` is the length of `value`
+    pub const fn push_bytes_n(self, value: &[u8]) -> Self {
+        self.push_word(&bytes_n_word(value))
+    }
+
+    /// Appends a `bytes` member, encoded as its Keccak-256 hash
+    pub const fn push_bytes(self, value: &[u8]) -> Self {
+        self.push_word(&Keccak256::new().update(value).finalize())
+    }
+
+    /// Appends a `string` member, encoded as its Keccak-256 hash
+    pub const fn push_string(self, value: &str) -> Self {
+        self.push_bytes(value.as_bytes())
+    }
+
+    /// Appends a struct member, encoded as its `hashStruct`
+    pub const fn push_struct(self, hash_struct: &[u8; 32]) -> Self {
+        self.push_word(hash_struct)
+    }
+
+    /// Appends an array member, encoded as the Keccak-256 hash of its encoded
+    /// elements
+    ///
+    /// The elements of atomic types are encoded as by the `indexed_*`
+    /// functions of [`solidity`](crate::solidity), `string` and `bytes`
+    /// elements by their hash and struct elements by their `hashStruct`.
+    pub const fn push_array(self, elements: &[[u8; 32]]) -> Self {
+        let mut keccak = Keccak256::new();
+        let mut i = 0;
+        while i < elements.len() {
+            keccak = keccak.update(&elements[i]);
+            i += 1;
+        }
+        self.push_word(&keccak.finalize())
+    }
+
+    /// Returns `hashStruct`
+    pub const fn finalize(self) -> [u8; 32] {
+        self.keccak.finalize()
+    }
+}
+
+/// Computes the signing digest `keccak256("\x19\x01" ‖ domainSeparator ‖
+/// hashStruct(message))`
+pub const fn digest(domain_separator: &[u8; 32], hash_struct: &[u8; 32]) -> [u8; 32] {
+    Keccak256::new()
+        .update(b"\x19\x01")
+        .update(domain_separator)
+        .update(hash_struct)
+        .finalize()
+}
+
+/// Computes the domain separator, `hashStruct(eip712Domain)`, of an
+/// `EIP712Domain` with the given fields
+///
+/// Fields that are `None` are left out of the type.
+pub const fn domain_separator(
+    name: Option<&str>,
+    version: Option<&str>,
+    chain_id: Option<u64>,
+    verifying_contract: Option<&Address>,
+    salt: Option<&[u8; 32]>,
+) -> [u8; 32] {
+    let fields: [(&[u8], bool); 5] = [
+        (b"string name", name.is_some()),
+        (b"string version", version.is_some()),
+        (b"uint256 chainId", chain_id.is_some()),
+        (b"address verifyingContract", verifying_contract.is_some()),
+        (b"bytes32 salt", salt.is_some()),
+    ];
+    let mut type_hash = Keccak256::new().update(b"EIP712Domain(");
+    let mut first = true;
+    let mut i = 0;
+    while i < fields.len() {
+        if fields[i].1 {
+            if !first {
+                type_hash = type_hash.update(b",");
+            }
+            type_hash = type_hash.update(fields[i].0);
+            first = false;
+        }
+        i += 1;
+    }
+    let mut hash_struct = HashStruct::new(&type_hash.update(b")").finalize());
+    if let Some(name) = name {
+        hash_struct = hash_struct.push_string(name);
+    }
+    if let Some(version) = version {
+        hash_struct = hash_struct.push_string(version);
+    }
+    if let Some(chain_id) = chain_id {
+        hash_struct = hash_struct.push_uint::<256>(chain_id as u128);
+    }
+    if let Some(verifying_contract) = verifying_contract {
+        hash_struct = hash_struct.push_address(verifying_contract);
+    }
+    if let Some(salt) = salt {
+        hash_struct = hash_struct.push_word(salt);
+    }
+    hash_struct.finalize()
+}
+
+/// Maximum length of the `encodeType` of an [`Eip712`] struct
+pub const MAX_ENCODE_TYPE_LEN: usize = 1024;
+
+/// A struct definition `Name(type name,...)` built in constant expressions
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct StructDefinition {
+    bytes: [u8; MAX_ENCODE_TYPE_LEN],
+    len: usize,
+}
+
+impl StructDefinition {
+    /// Constructs an empty definition
+    pub const fn new() -> StructDefinition {
+        StructDefinition {
+            bytes: [0; MAX_ENCODE_TYPE_LEN],
+            len: 0,
+        }
+    }
+
+    /// Appends bytes
+    pub const fn push_bytes(mut self, bytes: &[u8]) -> Self {
+        assert!(
+            self.len + bytes.len() <= MAX_ENCODE_TYPE_LEN,
+            "EIP-712 struct definition too long"
+        );
+        let mut i = 0;
+        while i < bytes.len() {
+            self.bytes[self.len] = bytes[i];
+            self.len += 1;
+            i += 1;
+        }
+        self
+    }
+
+    /// Appends a type name
+    pub const fn push_name(self, name: &AbiName) -> Self {
+        self.push_bytes(name.as_bytes())
+    }
+
+    /// Appends `member_type` in place of `field_type`, the type name of a
+    /// field
+    ///
+    /// `member_type` must be `field_type` or an integer type of the same
+    /// signedness, whose values are encoded with
+    /// [`encode_data_as`].
+    pub const fn push_type_override(self, field_type: &AbiName, member_type: &str) -> Self {
+        let member = member_type.as_bytes();
+        assert!(
+            !member.is_empty() && check_type(member, 0) == member.len(),
+            "invalid EIP-712 member type"
+        );
+        let mut base_end = 0;
+        while base_end < member.len() && member[base_end] != b'[' {
+            base_end += 1;
+        }
+        assert!(
+            !is_unsupported_type(member, 0, base_end),
+            "EIP-712 member types cannot be tuples, `function` or fixed-point"
+        );
+        let field = field_type.as_bytes();
+        let compatible = match (integer_type(field), integer_type(member)) {
+            (Some((field_signed, _)), Some((member_signed, _))) => field_signed == member_signed,
+            _ => compare(field, 0, field.len(), member, 0, member.len()).is_eq(),
+        };
+        assert!(
+            compatible,
+            "type override must be an integer type of the same signedness as the field"
+        );
+        self.push_bytes(member)
+    }
+
+    /// Returns the definition
+    pub const fn as_bytes(&self) -> &[u8] {
+        self.bytes.split_at(self.len).0
+    }
+}
+
+impl Default for StructDefinition {
+    fn default() -> Self {
+        StructDefinition::new()
+    }
+}
+
+/// A set of struct definitions built in constant expressions, the primary
+/// type first
+///
+/// The definitions share a buffer of [`MAX_ENCODE_TYPE_LEN`] bytes, as they
+/// all end up in the `encodeType` of the primary type.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct StructTypes {
+    bytes: [u8; MAX_ENCODE_TYPE_LEN],
+    ends: [usize; MAX_TYPES],
+    len: usize,
+}
+
+impl StructTypes {
+    /// Constructs an empty set
+    pub const fn new() -> StructTypes {
+        StructTypes {
+            bytes: [0; MAX_ENCODE_TYPE_LEN],
+            ends: [0; MAX_TYPES],
+            len: 0,
+        }
+    }
+
+    /// Returns the definition at `index`
+    const fn definition(&self, index: usize) -> &[u8] {
+        let start = if index == 0 { 0 } else { self.ends[index - 1] };
+        self.bytes.split_at(self.ends[index]).0.split_at(start).1
+    }
+
+    /// Adds a definition unless it is already in the set
+    pub const fn insert(self, definition: &StructDefinition) -> Self {
+        self.insert_bytes(definition.as_bytes())
+    }
+
+    const fn insert_bytes(mut self, definition: &[u8]) -> Self {
+        let mut i = 0;
+        while i < self.len {
+            let other = self.definition(i);
+            if compare(other, 0, other.len(), definition, 0, definition.len()).is_eq() {
+                return self;
+            }
+            i += 1;
+        }
+        assert!(self.len < MAX_TYPES, "too many EIP-712 struct types");
+        let mut end = if self.len == 0 {
+            0
+        } else {
+            self.ends[self.len - 1]
+        };
+        assert!(
+            end + definition.len() <= MAX_ENCODE_TYPE_LEN,
+            "EIP-712 struct definitions too long"
+        );
+        let mut i = 0;
+        while i < definition.len() {
+            self.bytes[end] = definition[i];
+            end += 1;
+            i += 1;
+        }
+        self.ends[self.len] = end;
+        self.len += 1;
+        self
+    }
+
+    /// Adds the definitions of `other`
+    pub const fn union(mut self, other: &StructTypes) -> Self {
+        let mut i = 0;
+        while i < other.len {
+            self = self.insert_bytes(other.definition(i));
+            i += 1;
+        }
+        self
+    }
+
+    /// Builds `encodeType` of the primary type
+    pub const fn encode_type<const N: usize>(&self) -> EncodeType<N> {
+        let mut types = [""; MAX_TYPES];
+        let mut i = 0;
+        while i < self.len {
+            types[i] = match core::str::from_utf8(self.definition(i)) {
+                Ok(definition) => definition,
+                Err(_) => panic!("invalid EIP-712 type definition"),
+            };
+            i += 1;
+        }
+        EncodeType::new(types.split_at(self.len).0)
+    }
+}
+
+impl Default for StructTypes {
+    fn default() -> Self {
+        StructTypes::new()
+    }
+}
+
+/// A type of EIP-712 struct members
+pub trait Eip712Type {
+    /// The type name in member declarations
+    const TYPE_NAME: AbiName;
+
+    /// The definitions of the struct types referenced by the type
+    const STRUCT_TYPES: StructTypes;
+
+    /// Encodes a member value, `encodeData` of a struct
+    fn encode_data(&self) -> [u8; 32];
+}
+
+/// An EIP-712 struct, usually implemented with `#[derive(Eip712)]`
+pub trait Eip712: Eip712Type {
+    /// `encodeType` of the struct
+    const ENCODE_TYPE: EncodeType<MAX_ENCODE_TYPE_LEN> = Self::STRUCT_TYPES.encode_type();
+
+    /// `typeHash` of the struct
+    const TYPE_HASH: [u8; 32] = Self::ENCODE_TYPE.type_hash();
+
+    /// Computes `hashStruct`
+    fn hash_struct(&self) -> [u8; 32];
+}
+
+/// Encodes a member value whose type `member_type` overrides the type name of
+/// `T`, as with [`StructDefinition::push_type_override`]
+///
+/// # Panics
+///
+/// Panics if the value does not fit in an integer `member_type`.
+pub fn encode_data_as<T: Eip712Type + ?Sized>(value: &T, member_type: &str) -> [u8; 32] {
+    let word = value.encode_data();
+    if let Some((signed, bits)) = integer_type(member_type.as_bytes()) {
+        let start = 32 - bits / 8;
+        let fill = if signed && word[0] & 0x80 != 0 {
+            0xff
+        } else {
+            0
+        };
+        let sign_bit = !signed || start == 0 || (word[start] ^ fill) & 0x80 == 0;
+        assert!(
+            word[..start].iter().all(|&b| b == fill) && sign_bit,
+            "value out of range for {member_type}"
+        );
+    }
+    word
+}
+
+macro_rules! eip712_type {
+    ($($ty:ty => |$value:ident| $encode:expr,)*) => {
+        $(
+            impl Eip712Type for $ty {
+                const TYPE_NAME: AbiName = <$ty as crate::sol_type::SolType>::ABI_NAME;
+                const STRUCT_TYPES: StructTypes = StructTypes::new();
+
+                fn encode_data(&self) -> [u8; 32] {
+                    let $value = self;
+                    $encode
+                }
+            }
+        )*
+    };
+}
+
+eip712_type!(
+    bool => |value| uint_word::<8>(*value as u128),
+    u8 => |value| uint_word::<8>(*value as u128),
+    u16 => |value| uint_word::<16>(*value as u128),
+    u32 => |value| uint_word::<32>(*value as u128),
+    u64 => |value| uint_word::<64>(*value as u128),
+    u128 => |value| uint_word::<128>(*value),
+    i8 => |value| int_word::<8>(*value as i128),
+    i16 => |value| int_word::<16>(*value as i128),
+    i32 => |value| int_word::<32>(*value as i128),
+    i64 => |value| int_word::<64>(*value as i128),
+    i128 => |value| int_word::<128>(*value),
+    U256 => |value| value.0,
+    I256 => |value| value.0,
+    Address => |value| address_word(value),
+    Bytes<'_> => |value| Keccak256::new().update(value.0).finalize(),
+    str => |value| Keccak256::new().update(value.as_bytes()).finalize(),
+);
+
+#[cfg(feature = "alloc")]
+eip712_type!(
+    alloc::string::String => |value| Keccak256::new().update(value.as_bytes()).finalize(),
+);
+
+impl<const N: usize> Eip712Type for FixedBytes<N> {
+    const TYPE_NAME: AbiName = <Self as crate::sol_type::SolType>::ABI_NAME;
+    const STRUCT_TYPES: StructTypes = StructTypes::new();
+
+    fn encode_data(&self) -> [u8; 32] {
+        bytes_n_word(&self.0)
+    }
+}
+
+impl<T: Eip712Type + ?Sized> Eip712Type for &T {
+    const TYPE_NAME: AbiName = T::TYPE_NAME;
+    const STRUCT_TYPES: StructTypes = T::STRUCT_TYPES;
+
+    fn encode_data(&self) -> [u8; 32] {
+        T::encode_data(self)
+    }
+}
+
+/// Encodes an array as the Keccak-256 hash of its encoded elements
+fn encode_array<T: Eip712Type>(elements: &[T]) -> [u8; 32] {
+    elements
+        .iter()
+        .fold(Keccak256::new(), |keccak, element| {
+            keccak.update(&element.encode_data())
+        })
+        .finalize()
+}
+
+impl<T: Eip712Type, const N: usize> Eip712Type for [T; N] {
+    const TYPE_NAME: AbiName = T::TYPE_NAME
+        .push_bytes(b"[")
+        .push_decimal(N)
+        .push_bytes(b"]");
+    const STRUCT_TYPES: StructTypes = T::STRUCT_TYPES;
+
+    fn encode_data(&self) -> [u8; 32] {
+        encode_array(self)
+    }
+}
+
+impl<T: Eip712Type> Eip712Type for [T] {
+    const TYPE_NAME: AbiName = T::TYPE_NAME.push_bytes(b"[]");
+    const STRUCT_TYPES: StructTypes = T::STRUCT_TYPES;
+
+    fn encode_data(&self) -> [u8; 32] {
+        encode_array(self)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: Eip712Type> Eip712Type for alloc::vec::Vec<T> {
+    const TYPE_NAME: AbiName = T::TYPE_NAME.push_bytes(b"[]");
+    const STRUCT_TYPES: StructTypes = T::STRUCT_TYPES;
+
+    fn encode_data(&self) -> [u8; 32] {
+        encode_array(self)
+    }
+}
@@ -0,0 +1,132 @@
+//! Message expansion for hashing to elliptic curves (RFC 9380)
+//!
+//! Domain separation tags longer than 255 bytes are hashed as described in
+//! section 5.3.3 of RFC 9380.
+//!
+//! # Examples
+//!
+//! ```rust
+//! # use keccak_const::hash_to_curve;
+//! const UNIFORM_BYTES: [u8; 32] =
+//!     hash_to_curve::expand_message_xof_shake128(b"", b"QUUX-V01-CS02-with-expander-SHAKE128");
+//!
+//! assert_eq!(
+//!     [
+//!         0x86, 0x51, 0x8c, 0x9c, 0xd8, 0x65, 0x81, 0x48, 0x6e, 0x94, 0x85, 0xaa, 0x74, 0xab,
+//!         0x35, 0xba, 0x15, 0x0d, 0x1c, 0x75, 0xc8, 0x8e, 0x26, 0xb7, 0x04, 0x3e, 0x44, 0xe2,
+//!         0xac, 0xd7, 0x35, 0xa2,
+//!     ],
+//!     UNIFORM_BYTES,
+//! );
+//! ```
+
+use crate::Sha3_256;
+use crate::Shake128;
+use crate::Shake256;
+
+const OVERSIZE_DST_PREFIX: &[u8] = b"H2C-OVERSIZE-DST-";
+
+const MAX_DST_LEN: usize = 255;
+
+/// `expand_message_xmd` with `SHA3-256` as the hash function
+///
+/// `LEN` must be at most `255 * 32`.
+pub const fn expand_message_xmd<const LEN: usize>(msg: &[u8], dst: &[u8]) -> [u8; LEN] {
+    let ell = LEN.div_ceil(32);
+    assert!(ell <= 255, "requested output too long");
+
+    let oversize_dst: [u8; 32];
+    let dst: &[u8] = if dst.len() > MAX_DST_LEN {
+        oversize_dst = Sha3_256::new()
+            .update(OVERSIZE_DST_PREFIX)
+            .update(dst)
+            .finalize();
+        &oversize_dst
+    } else {
+        dst
+    };
+    let dst_len = [dst.len() as u8];
+
+    let b_0 = Sha3_256::new()
+        .update(&[0; 136])
+        .update(msg)
+        .update(&(LEN as u16).to_be_bytes())
+        .update(&[0])
+        .update(dst)
+        .update(&dst_len)
+        .finalize();
+
+    let mut output = [0; LEN];
+    let mut b_i = [0; 32];
+    let mut i = 1;
+    while i <= ell {
+        let mut input = b_0;
+        let mut j = 0;
+        while j < input.len() {
+            input[j] ^= b_i[j];
+            j += 1;
+        }
+        b_i = Sha3_256::new()
+            .update(&input)
+            .update(&[i as u8])
+            .update(dst)
+            .update(&dst_len)
+            .finalize();
+        let mut j = 0;
+        while j < b_i.len() && (i - 1) * 32 + j < LEN {
+            output[(i - 1) * 32 + j] = b_i[j];
+            j += 1;
+        }
+        i += 1;
+    }
+    output
+}
+
+macro_rules! expand_message_xof {
+    (
+        $(#[$doc:meta])* $name:ident,
+        $xof:ident,
+        $oversize_dst_len:literal,
+    ) => {
+        $(#[$doc])*
+        ///
+        /// `LEN` must be at most `65535`.
+        pub const fn $name<const LEN: usize>(msg: &[u8], dst: &[u8]) -> [u8; LEN] {
+            assert!(LEN <= u16::MAX as usize, "requested output too long");
+
+            let oversize_dst: [u8; $oversize_dst_len];
+            let dst: &[u8] = if dst.len() > MAX_DST_LEN {
+                oversize_dst = $xof::new()
+                    .update(OVERSIZE_DST_PREFIX)
+                    .update(dst)
+                    .finalize();
+                &oversize_dst
+            } else {
+                dst
+            };
+
+            $xof::new()
+                .update(msg)
+                .update(&(LEN as u16).to_be_bytes())
+                .update(dst)
+                .update(&[dst.len() as u8])
+                .finalize()
+        }
+    };
+}
+
+expand_message_xof!(
+    /// `expand_message_xof` with `SHAKE128` for a target security level of
+    /// 128 bits
+    expand_message_xof_shake128,
+    Shake128,
+    32,
+);
+
+expand_message_xof!(
+    /// `expand_message_xof` with `SHAKE256` for a target security level of
+    /// 256 bits
+    expand_message_xof_shake256,
+    Shake256,
+    64,
+);
@@ -67,6 +67,24 @@ impl KeccakState {
         self
     }
 
+    /// XORs `input` into the partially absorbed block at `offset`
+    ///
+    /// The caller must have absorbed zeros there, in which case the state is
+    /// that of absorbing `input` in their place.
+    #[cfg_attr(not(feature = "std"), allow(dead_code))]
+    pub(crate) const fn xor_at(mut self, offset: usize, input: &[u8]) -> Self {
+        assert!(
+            offset + input.len() <= self.pos,
+            "input is outside the block"
+        );
+        let mut i = 0;
+        while i < input.len() {
+            self.state[offset + i] ^= input[i];
+            i += 1;
+        }
+        self
+    }
+
     /// Pad and squeeze the state to the output
     pub const fn finalize(&self) -> XofReader {
         let Self {
@@ -53,10 +53,30 @@
 
 #![no_std]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
+
+pub mod abi;
+pub mod address;
+mod der;
+pub mod eip191;
+pub mod eip712;
+pub mod hash_to_curve;
 mod keccak;
+pub mod lms;
+pub mod ml_dsa;
+pub mod ml_kem;
+pub mod selector_table;
+pub mod slh_dsa;
+pub mod sol_type;
+pub mod solidity;
+pub mod storage;
+pub mod stylus;
 
 use keccak::KeccakState;
-use keccak::XofReader;
+pub use keccak::XofReader;
 
 const PADDING_SHA3: u8 = 0x06;
 const PADDING_KECCAK: u8 = 0x01;
@@ -75,6 +95,7 @@ macro_rules! sha3 {
 
         impl $name {
             /// Constructs a new hasher
+            #[allow(clippy::new_without_default)]
             pub const fn new() -> $name {
                 $name {
                     state: KeccakState::new($security, $padding),
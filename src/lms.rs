@@ -0,0 +1,510 @@
+//! Leighton-Micali (LMS) and Hierarchical (HSS) hash-based signatures
+//! (RFC 8554) with the SHAKE256 parameter sets of NIST SP 800-208
+//!
+//! Private keys are derived from a seed as described in Appendix A of RFC
+//! 8554. Computing a public key evaluates the hash function about
+//! `2^h * p * 2^w` times, so compile-time computation is only practical for
+//! the smallest parameter sets.
+//!
+//! # Examples
+//!
+//! ```rust
+//! # use keccak_const::lms;
+//! # use keccak_const::lms::LmotsAlgorithm;
+//! # use keccak_const::lms::LmsAlgorithm;
+//! const LMS: LmsAlgorithm = LmsAlgorithm::ShakeM24H5;
+//! const LMOTS: LmotsAlgorithm = LmotsAlgorithm::ShakeN24W4;
+//!
+//! let public_key = lms::public_key::<{ LMS.public_key_len() }>(LMS, LMOTS, &[0; 16], &[0; 24]);
+//!
+//! assert!(!lms::verify(&public_key, b"message", &[0; LMS.signature_len(LMOTS)]));
+//! ```
+
+use crate::Shake256;
+
+const D_PBLC: [u8; 2] = [0x80, 0x80];
+const D_MESG: [u8; 2] = [0x81, 0x81];
+const D_LEAF: [u8; 2] = [0x82, 0x82];
+const D_INTR: [u8; 2] = [0x83, 0x83];
+
+/// Length of the key pair identifier `I`
+pub const ID_LEN: usize = 16;
+
+/// Largest hash output length of the supported parameter sets
+const MAX_N: usize = 32;
+
+/// Largest tree height of the supported parameter sets
+const MAX_H: usize = 25;
+
+/// A hash value of which the first `n` bytes are used
+type Node = [u8; MAX_N];
+
+/// LM-OTS one-time signature parameter sets
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[repr(u32)]
+pub enum LmotsAlgorithm {
+    ShakeN32W1 = 0x11,
+    ShakeN32W2 = 0x12,
+    ShakeN32W4 = 0x13,
+    ShakeN32W8 = 0x14,
+    ShakeN24W1 = 0x15,
+    ShakeN24W2 = 0x16,
+    ShakeN24W4 = 0x17,
+    ShakeN24W8 = 0x18,
+}
+
+impl LmotsAlgorithm {
+    /// Looks up a parameter set by its type code
+    pub const fn from_u32(ty: u32) -> Option<Self> {
+        match ty {
+            0x11 => Some(Self::ShakeN32W1),
+            0x12 => Some(Self::ShakeN32W2),
+            0x13 => Some(Self::ShakeN32W4),
+            0x14 => Some(Self::ShakeN32W8),
+            0x15 => Some(Self::ShakeN24W1),
+            0x16 => Some(Self::ShakeN24W2),
+            0x17 => Some(Self::ShakeN24W4),
+            0x18 => Some(Self::ShakeN24W8),
+            _ => None,
+        }
+    }
+
+    /// Number of bytes `n` of the hash output
+    pub const fn n(self) -> usize {
+        match self {
+            Self::ShakeN32W1 | Self::ShakeN32W2 | Self::ShakeN32W4 | Self::ShakeN32W8 => 32,
+            Self::ShakeN24W1 | Self::ShakeN24W2 | Self::ShakeN24W4 | Self::ShakeN24W8 => 24,
+        }
+    }
+
+    /// Width in bits `w` of the Winternitz coefficients
+    pub const fn w(self) -> usize {
+        match self {
+            Self::ShakeN32W1 | Self::ShakeN24W1 => 1,
+            Self::ShakeN32W2 | Self::ShakeN24W2 => 2,
+            Self::ShakeN32W4 | Self::ShakeN24W4 => 4,
+            Self::ShakeN32W8 | Self::ShakeN24W8 => 8,
+        }
+    }
+
+    /// Number `p` of `n`-byte elements in a signature
+    pub const fn p(self) -> usize {
+        match self {
+            Self::ShakeN32W1 => 265,
+            Self::ShakeN32W2 => 133,
+            Self::ShakeN32W4 => 67,
+            Self::ShakeN32W8 => 34,
+            Self::ShakeN24W1 => 200,
+            Self::ShakeN24W2 => 101,
+            Self::ShakeN24W4 => 51,
+            Self::ShakeN24W8 => 26,
+        }
+    }
+
+    /// Number of left-shift bits `ls` of the checksum
+    pub const fn ls(self) -> u32 {
+        match self {
+            Self::ShakeN32W1 => 7,
+            Self::ShakeN24W1 => 8,
+            Self::ShakeN32W2 | Self::ShakeN24W2 => 6,
+            Self::ShakeN32W4 | Self::ShakeN24W4 => 4,
+            Self::ShakeN32W8 | Self::ShakeN24W8 => 0,
+        }
+    }
+
+    /// Length of a LM-OTS signature
+    pub const fn signature_len(self) -> usize {
+        4 + self.n() * (self.p() + 1)
+    }
+}
+
+/// LMS parameter sets
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[repr(u32)]
+pub enum LmsAlgorithm {
+    ShakeM32H5 = 0x0f,
+    ShakeM32H10 = 0x10,
+    ShakeM32H15 = 0x11,
+    ShakeM32H20 = 0x12,
+    ShakeM32H25 = 0x13,
+    ShakeM24H5 = 0x14,
+    ShakeM24H10 = 0x15,
+    ShakeM24H15 = 0x16,
+    ShakeM24H20 = 0x17,
+    ShakeM24H25 = 0x18,
+}
+
+impl LmsAlgorithm {
+    /// Looks up a parameter set by its type code
+    pub const fn from_u32(ty: u32) -> Option<Self> {
+        match ty {
+            0x0f => Some(Self::ShakeM32H5),
+            0x10 => Some(Self::ShakeM32H10),
+            0x11 => Some(Self::ShakeM32H15),
+            0x12 => Some(Self::ShakeM32H20),
+            0x13 => Some(Self::ShakeM32H25),
+            0x14 => Some(Self::ShakeM24H5),
+            0x15 => Some(Self::ShakeM24H10),
+            0x16 => Some(Self::ShakeM24H15),
+            0x17 => Some(Self::ShakeM24H20),
+            0x18 => Some(Self::ShakeM24H25),
+            _ => None,
+        }
+    }
+
+    /// Number of bytes `m` of a tree node
+    pub const fn m(self) -> usize {
+        if (self as u32) < 0x14 {
+            32
+        } else {
+            24
+        }
+    }
+
+    /// Height `h` of the tree
+    pub const fn h(self) -> usize {
+        5 * ((self as u32 - 0x0f) % 5 + 1) as usize
+    }
+
+    /// Length of a LMS public key
+    pub const fn public_key_len(self) -> usize {
+        4 + 4 + ID_LEN + self.m()
+    }
+
+    /// Length of a LMS signature using `lmots` one-time signatures
+    pub const fn signature_len(self, lmots: LmotsAlgorithm) -> usize {
+        4 + lmots.signature_len() + 4 + self.h() * self.m()
+    }
+}
+
+const fn read_u32(bytes: &[u8], offset: usize) -> Option<u32> {
+    if bytes.len() < offset + 4 {
+        return None;
+    }
+    Some(u32::from_be_bytes([
+        bytes[offset],
+        bytes[offset + 1],
+        bytes[offset + 2],
+        bytes[offset + 3],
+    ]))
+}
+
+const fn truncate(node: &Node, n: usize) -> &[u8] {
+    node.split_at(n).0
+}
+
+const fn eq(a: &[u8], b: &[u8]) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+    let mut i = 0;
+    while i < a.len() {
+        if a[i] != b[i] {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+/// `coef(S, i, w)`
+const fn coef(s: &[u8], i: usize, w: usize) -> usize {
+    let shift = 8 - (w * (i % (8 / w)) + w);
+    (s[i * w / 8] as usize >> shift) & ((1 << w) - 1)
+}
+
+/// Concatenation `Q || Cksm(Q)` of a message hash and its checksum
+const fn digits(lmots: LmotsAlgorithm, q_hash: &Node) -> [u8; MAX_N + 2] {
+    let n = lmots.n();
+    let w = lmots.w();
+    let mut sum = 0;
+    let mut i = 0;
+    while i < n * 8 / w {
+        sum += (1 << w) - 1 - coef(q_hash, i, w);
+        i += 1;
+    }
+    let checksum = ((sum << lmots.ls()) as u16).to_be_bytes();
+    let mut v = [0; MAX_N + 2];
+    let mut i = 0;
+    while i < n {
+        v[i] = q_hash[i];
+        i += 1;
+    }
+    v[n] = checksum[0];
+    v[n + 1] = checksum[1];
+    v
+}
+
+/// Iterates the chain function from step `start` up to, excluding, `end`
+const fn chain(
+    lmots: LmotsAlgorithm,
+    id: &[u8],
+    q: u32,
+    i: usize,
+    mut tmp: Node,
+    start: usize,
+    end: usize,
+) -> Node {
+    let mut j = start;
+    while j < end {
+        tmp = Shake256::new()
+            .update(id)
+            .update(&q.to_be_bytes())
+            .update(&(i as u16).to_be_bytes())
+            .update(&[j as u8])
+            .update(truncate(&tmp, lmots.n()))
+            .finalize();
+        j += 1;
+    }
+    tmp
+}
+
+/// Computes the LM-OTS public key `K` of leaf `q`
+const fn lmots_public_key(lmots: LmotsAlgorithm, id: &[u8], q: u32, seed: &[u8]) -> Node {
+    let mut k = Shake256::new()
+        .update(id)
+        .update(&q.to_be_bytes())
+        .update(&D_PBLC);
+    let mut i = 0;
+    while i < lmots.p() {
+        let x = Shake256::new()
+            .update(id)
+            .update(&q.to_be_bytes())
+            .update(&(i as u16).to_be_bytes())
+            .update(&[0xff])
+            .update(seed)
+            .finalize();
+        let y = chain(lmots, id, q, i, x, 0, (1 << lmots.w()) - 1);
+        k = k.update(truncate(&y, lmots.n()));
+        i += 1;
+    }
+    k.finalize()
+}
+
+/// Computes the candidate LM-OTS public key `Kc` from a LM-OTS signature
+const fn lmots_candidate_public_key(
+    lmots: LmotsAlgorithm,
+    id: &[u8],
+    q: u32,
+    message: &[u8],
+    signature: &[u8],
+) -> Node {
+    let n = lmots.n();
+    let (c, y) = signature.split_at(4).1.split_at(n);
+    let q_hash = Shake256::new()
+        .update(id)
+        .update(&q.to_be_bytes())
+        .update(&D_MESG)
+        .update(c)
+        .update(message)
+        .finalize();
+    let v = digits(lmots, &q_hash);
+    let mut k = Shake256::new()
+        .update(id)
+        .update(&q.to_be_bytes())
+        .update(&D_PBLC);
+    let mut i = 0;
+    while i < lmots.p() {
+        let mut tmp = [0; MAX_N];
+        let mut j = 0;
+        while j < n {
+            tmp[j] = y[i * n + j];
+            j += 1;
+        }
+        let z = chain(
+            lmots,
+            id,
+            q,
+            i,
+            tmp,
+            coef(&v, i, lmots.w()),
+            (1 << lmots.w()) - 1,
+        );
+        k = k.update(truncate(&z, n));
+        i += 1;
+    }
+    k.finalize()
+}
+
+const fn leaf(id: &[u8], r: u32, k: &Node, m: usize) -> Node {
+    Shake256::new()
+        .update(id)
+        .update(&r.to_be_bytes())
+        .update(&D_LEAF)
+        .update(truncate(k, m))
+        .finalize()
+}
+
+const fn interior(id: &[u8], r: u32, left: &Node, right: &Node, m: usize) -> Node {
+    Shake256::new()
+        .update(id)
+        .update(&r.to_be_bytes())
+        .update(&D_INTR)
+        .update(truncate(left, m))
+        .update(truncate(right, m))
+        .finalize()
+}
+
+/// Computes the LMS public key `u32str(type) || u32str(otstype) || I || T[1]`
+/// of the private key derived from `seed`
+///
+/// `LEN` must be equal to [`LmsAlgorithm::public_key_len`].
+pub const fn public_key<const LEN: usize>(
+    lms: LmsAlgorithm,
+    lmots: LmotsAlgorithm,
+    id: &[u8; ID_LEN],
+    seed: &[u8],
+) -> [u8; LEN] {
+    let m = lms.m();
+    let h = lms.h();
+    assert!(LEN == lms.public_key_len(), "invalid public key length");
+    assert!(lmots.n() == m, "mismatched LMS and LM-OTS parameter sets");
+    assert!(seed.len() == m, "invalid seed length");
+
+    // computes the root with a stack of pending subtree roots
+    let mut stack = [[0; MAX_N]; MAX_H + 1];
+    let mut heights = [0; MAX_H + 1];
+    let mut top = 0;
+    let mut q = 0;
+    while q < 1 << h {
+        let k = lmots_public_key(lmots, id, q, seed);
+        stack[top] = leaf(id, (1 << h) + q, &k, m);
+        heights[top] = 0;
+        top += 1;
+        while top >= 2 && heights[top - 2] == heights[top - 1] {
+            let height = heights[top - 1] + 1;
+            let r = ((1 << h) + q) >> height;
+            stack[top - 2] = interior(id, r, &stack[top - 2], &stack[top - 1], m);
+            heights[top - 2] = height;
+            top -= 1;
+        }
+        q += 1;
+    }
+
+    let mut output = [0; LEN];
+    let (ty, ots_ty) = ((lms as u32).to_be_bytes(), (lmots as u32).to_be_bytes());
+    let mut i = 0;
+    while i < 4 {
+        output[i] = ty[i];
+        output[4 + i] = ots_ty[i];
+        i += 1;
+    }
+    let mut i = 0;
+    while i < ID_LEN {
+        output[8 + i] = id[i];
+        i += 1;
+    }
+    let mut i = 0;
+    while i < m {
+        output[8 + ID_LEN + i] = stack[0][i];
+        i += 1;
+    }
+    output
+}
+
+/// Returns the length of the LMS signature at the start of `signature`
+const fn signature_len(signature: &[u8]) -> Option<usize> {
+    let Some(lmots) = read_u32(signature, 4) else {
+        return None;
+    };
+    let Some(lmots) = LmotsAlgorithm::from_u32(lmots) else {
+        return None;
+    };
+    let Some(lms) = read_u32(signature, 4 + lmots.signature_len()) else {
+        return None;
+    };
+    match LmsAlgorithm::from_u32(lms) {
+        Some(lms) => Some(lms.signature_len(lmots)),
+        None => None,
+    }
+}
+
+/// Verifies a LMS signature of `message`
+pub const fn verify(public_key: &[u8], message: &[u8], signature: &[u8]) -> bool {
+    let (Some(lms), Some(lmots)) = (read_u32(public_key, 0), read_u32(public_key, 4)) else {
+        return false;
+    };
+    let (Some(lms), Some(lmots)) = (LmsAlgorithm::from_u32(lms), LmotsAlgorithm::from_u32(lmots))
+    else {
+        return false;
+    };
+    let m = lms.m();
+    let h = lms.h();
+    if lmots.n() != m
+        || public_key.len() != lms.public_key_len()
+        || signature.len() != lms.signature_len(lmots)
+    {
+        return false;
+    }
+    let (id, root) = public_key.split_at(8).1.split_at(ID_LEN);
+
+    let Some(q) = read_u32(signature, 0) else {
+        return false;
+    };
+    let (lmots_signature, rest) = signature.split_at(4).1.split_at(lmots.signature_len());
+    if q >= 1 << h
+        || !matches!(read_u32(lmots_signature, 0), Some(ty) if ty == lmots as u32)
+        || !matches!(read_u32(rest, 0), Some(ty) if ty == lms as u32)
+    {
+        return false;
+    }
+    let path = rest.split_at(4).1;
+
+    let k = lmots_candidate_public_key(lmots, id, q, message, lmots_signature);
+    let mut r = (1 << h) + q;
+    let mut tmp = leaf(id, r, &k, m);
+    let mut i = 0;
+    while r > 1 {
+        let mut sibling = [0; MAX_N];
+        let mut j = 0;
+        while j < m {
+            sibling[j] = path[i * m + j];
+            j += 1;
+        }
+        tmp = if r % 2 == 1 {
+            interior(id, r / 2, &sibling, &tmp, m)
+        } else {
+            interior(id, r / 2, &tmp, &sibling, m)
+        };
+        r /= 2;
+        i += 1;
+    }
+    eq(truncate(&tmp, m), root)
+}
+
+/// Verifies a HSS signature of `message`
+pub const fn hss_verify(public_key: &[u8], message: &[u8], signature: &[u8]) -> bool {
+    let (Some(levels), Some(nspk)) = (read_u32(public_key, 0), read_u32(signature, 0)) else {
+        return false;
+    };
+    if levels == 0 || levels > 8 || nspk != levels - 1 {
+        return false;
+    }
+    let mut key = public_key.split_at(4).1;
+    let mut rest = signature.split_at(4).1;
+    let mut i = 0;
+    while i < nspk {
+        let Some(len) = signature_len(rest) else {
+            return false;
+        };
+        let Some((lms_signature, tail)) = rest.split_at_checked(len) else {
+            return false;
+        };
+        let Some(lms) = read_u32(tail, 0) else {
+            return false;
+        };
+        let Some(lms) = LmsAlgorithm::from_u32(lms) else {
+            return false;
+        };
+        let Some((next_key, tail)) = tail.split_at_checked(lms.public_key_len()) else {
+            return false;
+        };
+        if !verify(key, next_key, lms_signature) {
+            return false;
+        }
+        key = next_key;
+        rest = tail;
+        i += 1;
+    }
+    verify(key, message, rest)
+}
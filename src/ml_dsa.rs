@@ -0,0 +1,240 @@
+//! SHAKE based expansion routines of ML-DSA (FIPS 204)
+//!
+//! Each parameter set has its own module with the routines specialized to
+//! its dimensions.
+//!
+//! # Examples
+//!
+//! ```rust
+//! # use keccak_const::ml_dsa::ml_dsa_44;
+//! const RHO: [u8; 32] = [0; 32];
+//! const A_HAT: [[[i32; 256]; ml_dsa_44::L]; ml_dsa_44::K] = ml_dsa_44::expand_a(&RHO);
+//!
+//! assert!(A_HAT.iter().flatten().flatten().all(|&c| (0..8380417).contains(&c)));
+//! ```
+
+use crate::Shake128;
+use crate::Shake256;
+
+/// The ML-DSA modulus `q`
+pub const Q: i32 = 8380417;
+
+/// Number of coefficients of a polynomial
+pub const N: usize = 256;
+
+/// `RejNTTPoly(ρ || s || r)`
+const fn rej_ntt_poly(rho: &[u8; 32], s: u8, r: u8) -> [i32; N] {
+    let mut reader = Shake128::new().update(rho).update(&[s, r]).finalize_xof();
+    let mut a = [0; N];
+    let mut j = 0;
+    while j < N {
+        let b: [u8; 3];
+        (reader, b) = reader.read();
+        let z = ((b[2] as i32 & 0x7f) << 16) | ((b[1] as i32) << 8) | b[0] as i32;
+        if z < Q {
+            a[j] = z;
+            j += 1;
+        }
+    }
+    a
+}
+
+/// `CoeffFromHalfByte(b)`
+const fn coeff_from_half_byte(b: u8, eta: i32) -> Option<i32> {
+    match eta {
+        2 if b < 15 => Some(2 - (b % 5) as i32),
+        4 if b < 9 => Some(4 - b as i32),
+        _ => None,
+    }
+}
+
+/// `RejBoundedPoly(ρ' || r)`
+const fn rej_bounded_poly(rho_prime: &[u8; 64], r: u16, eta: i32) -> [i32; N] {
+    let mut reader = Shake256::new()
+        .update(rho_prime)
+        .update(&r.to_le_bytes())
+        .finalize_xof();
+    let mut a = [0; N];
+    let mut j = 0;
+    while j < N {
+        let z: [u8; 1];
+        (reader, z) = reader.read();
+        if let Some(z0) = coeff_from_half_byte(z[0] % 16, eta) {
+            a[j] = z0;
+            j += 1;
+        }
+        if let Some(z1) = coeff_from_half_byte(z[0] / 16, eta) {
+            if j < N {
+                a[j] = z1;
+                j += 1;
+            }
+        }
+    }
+    a
+}
+
+/// Samples a polynomial of `ExpandMask` with coefficients in `(-γ1, γ1]`
+const fn mask_poly(rho_double_prime: &[u8; 64], nonce: u16, gamma1_bits: u32) -> [i32; N] {
+    let mut reader = Shake256::new()
+        .update(rho_double_prime)
+        .update(&nonce.to_le_bytes())
+        .finalize_xof();
+    let bits = gamma1_bits + 1;
+    let mut y = [0; N];
+    let mut acc = 0u64;
+    let mut acc_bits = 0;
+    let mut i = 0;
+    while i < N {
+        while acc_bits < bits {
+            let b: [u8; 1];
+            (reader, b) = reader.read();
+            acc |= (b[0] as u64) << acc_bits;
+            acc_bits += 8;
+        }
+        y[i] = (1 << gamma1_bits) - (acc & ((1 << bits) - 1)) as i32;
+        acc >>= bits;
+        acc_bits -= bits;
+        i += 1;
+    }
+    y
+}
+
+/// `SampleInBall(ρ)`: samples a polynomial with `tau` coefficients in
+/// `{-1, 1}` and all others zero
+const fn sample_in_ball(rho: &[u8], tau: usize) -> [i32; N] {
+    let reader = Shake256::new().update(rho).finalize_xof();
+    let (mut reader, s) = reader.read::<8>();
+    let h = u64::from_le_bytes(s);
+    let mut c = [0; N];
+    let mut i = N - tau;
+    while i < N {
+        let mut j: [u8; 1];
+        loop {
+            (reader, j) = reader.read();
+            if j[0] as usize <= i {
+                break;
+            }
+        }
+        let j = j[0] as usize;
+        c[i] = c[j];
+        c[j] = if (h >> (i + tau - N)) & 1 == 1 { -1 } else { 1 };
+        i += 1;
+    }
+    c
+}
+
+macro_rules! parameter_set {
+    (
+        $(#[$doc:meta])* $name:ident,
+        k: $k:literal,
+        l: $l:literal,
+        eta: $eta:literal,
+        tau: $tau:literal,
+        gamma1_bits: $gamma1_bits:literal,
+        lambda: $lambda:literal,
+    ) => {
+        $(#[$doc])*
+        pub mod $name {
+            use super::N;
+
+            /// Number of rows of the matrix `A`
+            pub const K: usize = $k;
+
+            /// Number of columns of the matrix `A`
+            pub const L: usize = $l;
+
+            /// Bound `η` of the secret key coefficients
+            pub const ETA: i32 = $eta;
+
+            /// Number of nonzero coefficients of the challenge polynomial
+            pub const TAU: usize = $tau;
+
+            /// Bound `γ1` of the mask coefficients
+            pub const GAMMA1: i32 = 1 << $gamma1_bits;
+
+            /// Length in bytes of the commitment hash `c̃`
+            pub const C_TILDE_LEN: usize = $lambda / 4;
+
+            /// `ExpandA(ρ)`: expands the matrix `Â` in NTT representation
+            pub const fn expand_a(rho: &[u8; 32]) -> [[[i32; N]; L]; K] {
+                let mut a = [[[0; N]; L]; K];
+                let mut r = 0;
+                while r < K {
+                    let mut s = 0;
+                    while s < L {
+                        a[r][s] = super::rej_ntt_poly(rho, s as u8, r as u8);
+                        s += 1;
+                    }
+                    r += 1;
+                }
+                a
+            }
+
+            /// `ExpandS(ρ')`: expands the secret vectors `s1` and `s2`
+            pub const fn expand_s(rho_prime: &[u8; 64]) -> ([[i32; N]; L], [[i32; N]; K]) {
+                let mut s1 = [[0; N]; L];
+                let mut r = 0;
+                while r < L {
+                    s1[r] = super::rej_bounded_poly(rho_prime, r as u16, ETA);
+                    r += 1;
+                }
+                let mut s2 = [[0; N]; K];
+                let mut r = 0;
+                while r < K {
+                    s2[r] = super::rej_bounded_poly(rho_prime, (r + L) as u16, ETA);
+                    r += 1;
+                }
+                (s1, s2)
+            }
+
+            /// `ExpandMask(ρ'', μ)`: expands the mask vector `y`
+            pub const fn expand_mask(rho_double_prime: &[u8; 64], mu: u16) -> [[i32; N]; L] {
+                let mut y = [[0; N]; L];
+                let mut r = 0;
+                while r < L {
+                    y[r] = super::mask_poly(rho_double_prime, mu + r as u16, $gamma1_bits);
+                    r += 1;
+                }
+                y
+            }
+
+            /// `SampleInBall(c̃)`: samples the challenge polynomial
+            pub const fn sample_in_ball(c_tilde: &[u8; C_TILDE_LEN]) -> [i32; N] {
+                super::sample_in_ball(c_tilde, TAU)
+            }
+        }
+    };
+}
+
+parameter_set!(
+    /// The `ML-DSA-44` parameter set
+    ml_dsa_44,
+    k: 4,
+    l: 4,
+    eta: 2,
+    tau: 39,
+    gamma1_bits: 17,
+    lambda: 128,
+);
+
+parameter_set!(
+    /// The `ML-DSA-65` parameter set
+    ml_dsa_65,
+    k: 6,
+    l: 5,
+    eta: 4,
+    tau: 49,
+    gamma1_bits: 19,
+    lambda: 192,
+);
+
+parameter_set!(
+    /// The `ML-DSA-87` parameter set
+    ml_dsa_87,
+    k: 8,
+    l: 7,
+    eta: 2,
+    tau: 60,
+    gamma1_bits: 19,
+    lambda: 256,
+);
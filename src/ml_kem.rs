@@ -0,0 +1,109 @@
+//! Hash functions and sampling routines of ML-KEM (FIPS 203)
+//!
+//! # Examples
+//!
+//! ```rust
+//! # use keccak_const::ml_kem;
+//! const RHO: [u8; 32] = [0; 32];
+//! const A: [[[u16; 256]; 2]; 2] = ml_kem::expand_matrix(&RHO);
+//!
+//! assert_eq!(A[1][0], ml_kem::sample_ntt(&RHO, 1, 0));
+//! ```
+
+use crate::Sha3_256;
+use crate::Sha3_512;
+use crate::Shake128;
+use crate::Shake256;
+use crate::XofReader;
+
+/// The ML-KEM modulus `q`
+pub const Q: u16 = 3329;
+
+/// Number of coefficients of a polynomial
+pub const N: usize = 256;
+
+/// `H(s) = SHA3-256(s)`
+pub const fn h(s: &[u8]) -> [u8; 32] {
+    Sha3_256::new().update(s).finalize()
+}
+
+/// `J(s) = SHAKE256(s, 8 * 32)`
+pub const fn j(s: &[u8]) -> [u8; 32] {
+    Shake256::new().update(s).finalize()
+}
+
+/// `G(c) = SHA3-512(c)` split into two 32-byte halves
+pub const fn g(c: &[u8]) -> ([u8; 32], [u8; 32]) {
+    let digest = Sha3_512::new().update(c).finalize();
+    let mut a = [0; 32];
+    let mut b = [0; 32];
+    let mut i = 0;
+    while i < 32 {
+        a[i] = digest[i];
+        b[i] = digest[32 + i];
+        i += 1;
+    }
+    (a, b)
+}
+
+/// `PRF_η(s, b) = SHAKE256(s || b, 8 * 64 * η)`
+///
+/// `OUT` must be `64 * η` for `η` in `{2, 3}`.
+pub const fn prf<const OUT: usize>(s: &[u8; 32], b: u8) -> [u8; OUT] {
+    assert!(OUT == 64 * 2 || OUT == 64 * 3, "invalid PRF output length");
+    Shake256::new().update(s).update(&[b]).finalize()
+}
+
+/// `XOF`: SHAKE128 absorbing `input`
+///
+/// Output is squeezed incrementally through the returned reader.
+pub const fn xof(input: &[u8]) -> XofReader {
+    Shake128::new().update(input).finalize_xof()
+}
+
+/// `SampleNTT(ρ || j || i)`: rejection samples the NTT representation of
+/// the matrix entry `Â[i, j]`
+pub const fn sample_ntt(rho: &[u8; 32], i: u8, j: u8) -> [u16; N] {
+    let mut seed = [0; 34];
+    let mut k = 0;
+    while k < rho.len() {
+        seed[k] = rho[k];
+        k += 1;
+    }
+    seed[32] = j;
+    seed[33] = i;
+
+    let mut reader = xof(&seed);
+    let mut a = [0; N];
+    let mut k = 0;
+    while k < N {
+        let c: [u8; 3];
+        (reader, c) = reader.read();
+        let d1 = c[0] as u16 + 256 * (c[1] as u16 % 16);
+        let d2 = c[1] as u16 / 16 + 16 * c[2] as u16;
+        if d1 < Q {
+            a[k] = d1;
+            k += 1;
+        }
+        if d2 < Q && k < N {
+            a[k] = d2;
+            k += 1;
+        }
+    }
+    a
+}
+
+/// Expands the `K`×`K` matrix `Â` from the seed `ρ`
+pub const fn expand_matrix<const K: usize>(rho: &[u8; 32]) -> [[[u16; N]; K]; K] {
+    let mut a = [[[0; N]; K]; K];
+    let mut i = 0;
+    while i < K {
+        let mut j = 0;
+        while j < K {
+            a[i][j] = sample_ntt(rho, i as u8, j as u8);
+            j += 1;
+        }
+        i += 1;
+    }
+    a
+}
@@ -0,0 +1,131 @@
+//! Sorted dispatch tables mapping function selectors to handler indices
+//!
+//! The handler index of a function is the position of its signature in the
+//! list the table is built from. Two signatures with the same selector panic
+//! with a message naming both, which fails compilation when the table is a
+//! constant.
+//!
+//! # Examples
+//!
+//! ```rust
+//! # use keccak_const::selector_table::SelectorTable;
+//! const ROUTER: SelectorTable<3> = SelectorTable::new(&[
+//!     "transfer(address,uint256)",
+//!     "approve(address,uint256)",
+//!     "balanceOf(address)",
+//! ]);
+//!
+//! assert_eq!(Some(0), ROUTER.lookup(&[0xa9, 0x05, 0x9c, 0xbb, 0x00]));
+//! assert_eq!(Some(2), ROUTER.lookup(&[0x70, 0xa0, 0x82, 0x31]));
+//! assert_eq!(None, ROUTER.lookup(&[0x18, 0x16, 0x0d, 0xdd]));
+//! ```
+//!
+//! ```compile_fail
+//! # use keccak_const::selector_table::SelectorTable;
+//! // both selectors are 0x42966c68
+//! const ROUTER: SelectorTable<2> =
+//!     SelectorTable::new(&["burn(uint256)", "collate_propagate_storage(bytes16)"]);
+//! ```
+
+use crate::solidity::function_selector;
+
+/// A table of function selectors sorted for binary search
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SelectorTable<const N: usize> {
+    selectors: [u32; N],
+    indices: [usize; N],
+}
+
+impl<const N: usize> SelectorTable<N> {
+    /// Builds the table from canonical function signatures
+    pub const fn new(signatures: &[&str; N]) -> SelectorTable<N> {
+        let mut selectors = [0; N];
+        let mut indices = [0; N];
+        let mut i = 0;
+        while i < N {
+            let selector = u32::from_be_bytes(function_selector(signatures[i]));
+            // insertion sort, the tables are small
+            let mut j = i;
+            while j > 0 && selectors[j - 1] >= selector {
+                if selectors[j - 1] == selector {
+                    collision(signatures[indices[j - 1]], signatures[i]);
+                }
+                selectors[j] = selectors[j - 1];
+                indices[j] = indices[j - 1];
+                j -= 1;
+            }
+            selectors[j] = selector;
+            indices[j] = i;
+            i += 1;
+        }
+        SelectorTable { selectors, indices }
+    }
+
+    /// Returns the handler index of the selector at the start of `calldata`
+    pub const fn lookup(&self, calldata: &[u8]) -> Option<usize> {
+        match calldata.split_first_chunk() {
+            Some((selector, _)) => self.lookup_selector(*selector),
+            None => None,
+        }
+    }
+
+    /// Returns the handler index of `selector`
+    pub const fn lookup_selector(&self, selector: [u8; 4]) -> Option<usize> {
+        let selector = u32::from_be_bytes(selector);
+        let mut low = 0;
+        let mut high = N;
+        while low < high {
+            let mid = low + (high - low) / 2;
+            if self.selectors[mid] < selector {
+                low = mid + 1;
+            } else if self.selectors[mid] > selector {
+                high = mid;
+            } else {
+                return Some(self.indices[mid]);
+            }
+        }
+        None
+    }
+
+    /// Returns the selectors in ascending order
+    pub const fn selectors(&self) -> [[u8; 4]; N] {
+        let mut selectors = [[0; 4]; N];
+        let mut i = 0;
+        while i < N {
+            selectors[i] = self.selectors[i].to_be_bytes();
+            i += 1;
+        }
+        selectors
+    }
+
+    /// Returns the handler indices in the order of
+    /// [`selectors`](Self::selectors)
+    pub const fn indices(&self) -> &[usize; N] {
+        &self.indices
+    }
+}
+
+/// Panics with a message naming both colliding signatures
+const fn collision(first: &str, second: &str) -> ! {
+    const PREFIX: &[u8] = b"selector collision between `";
+    const INFIX: &[u8] = b"` and `";
+    let mut message = [0; 512];
+    let mut len = 0;
+    let mut parts = [PREFIX, first.as_bytes(), INFIX, second.as_bytes(), b"`"];
+    let mut i = 0;
+    while i < parts.len() {
+        // signatures are ASCII, so truncating cannot split a character
+        while let Some((&byte, rest)) = parts[i].split_first() {
+            if len < message.len() {
+                message[len] = byte;
+                len += 1;
+            }
+            parts[i] = rest;
+        }
+        i += 1;
+    }
+    match core::str::from_utf8(message.split_at(len).0) {
+        Ok(message) => panic!("{}", message),
+        Err(_) => unreachable!(),
+    }
+}
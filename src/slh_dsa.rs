@@ -0,0 +1,215 @@
+//! SHAKE instantiation of the SLH-DSA (FIPS 205) hash functions and the
+//! address structure `ADRS`
+//!
+//! The security parameter `n` is the length `N` of the seeds and hash values.
+//!
+//! # Examples
+//!
+//! ```rust
+//! # use keccak_const::slh_dsa;
+//! # use keccak_const::slh_dsa::Adrs;
+//! // Computes a WOTS+ chain of length 3 at compile time
+//! const PK_SEED: [u8; 16] = [0x42; 16];
+//! const CHAIN_END: [u8; 16] = {
+//!     let mut adrs = Adrs::new().set_chain_address(5);
+//!     let mut tmp = [0; 16];
+//!     let mut i = 0;
+//!     while i < 3 {
+//!         adrs = adrs.set_hash_address(i);
+//!         tmp = slh_dsa::f(&PK_SEED, &adrs, &tmp);
+//!         i += 1;
+//!     }
+//!     tmp
+//! };
+//!
+//! assert_eq!(
+//!     [
+//!         0xcf, 0xc8, 0xc8, 0x1b, 0xaa, 0x90, 0x97, 0xc9, 0xf1, 0xe7, 0x02, 0x81, 0xf4, 0x0e,
+//!         0x8e, 0x04,
+//!     ],
+//!     CHAIN_END,
+//! );
+//! ```
+
+use crate::Shake256;
+
+/// The address type of the `ADRS` structure
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[repr(u32)]
+pub enum AdrsType {
+    WotsHash = 0,
+    WotsPk = 1,
+    Tree = 2,
+    ForsTree = 3,
+    ForsRoots = 4,
+    WotsPrf = 5,
+    ForsPrf = 6,
+}
+
+/// The 32-byte address structure `ADRS`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Adrs([u8; 32]);
+
+impl Adrs {
+    /// Constructs a new address with all fields set to zero
+    pub const fn new() -> Adrs {
+        Adrs([0; 32])
+    }
+
+    /// Returns the byte representation of the address
+    pub const fn to_bytes(&self) -> [u8; 32] {
+        self.0
+    }
+
+    const fn set_word(mut self, offset: usize, value: u32) -> Self {
+        let bytes = value.to_be_bytes();
+        let mut i = 0;
+        while i < bytes.len() {
+            self.0[offset + i] = bytes[i];
+            i += 1;
+        }
+        self
+    }
+
+    const fn word(&self, offset: usize) -> u32 {
+        u32::from_be_bytes([
+            self.0[offset],
+            self.0[offset + 1],
+            self.0[offset + 2],
+            self.0[offset + 3],
+        ])
+    }
+
+    /// `ADRS.setLayerAddress(l)`
+    pub const fn set_layer_address(self, layer: u32) -> Self {
+        self.set_word(0, layer)
+    }
+
+    /// `ADRS.setTreeAddress(t)`
+    pub const fn set_tree_address(mut self, tree: u64) -> Self {
+        // the 12-byte field is wider than any tree address in FIPS 205
+        self = self.set_word(4, 0);
+        self = self.set_word(8, (tree >> 32) as u32);
+        self.set_word(12, tree as u32)
+    }
+
+    /// `ADRS.setTypeAndClear(Y)`
+    pub const fn set_type_and_clear(mut self, ty: AdrsType) -> Self {
+        self = self.set_word(16, ty as u32);
+        self = self.set_word(20, 0);
+        self = self.set_word(24, 0);
+        self.set_word(28, 0)
+    }
+
+    /// `ADRS.setKeyPairAddress(i)`
+    pub const fn set_key_pair_address(self, i: u32) -> Self {
+        self.set_word(20, i)
+    }
+
+    /// `ADRS.setChainAddress(i)`
+    pub const fn set_chain_address(self, i: u32) -> Self {
+        self.set_word(24, i)
+    }
+
+    /// `ADRS.setTreeHeight(i)`
+    pub const fn set_tree_height(self, i: u32) -> Self {
+        self.set_word(24, i)
+    }
+
+    /// `ADRS.setHashAddress(i)`
+    pub const fn set_hash_address(self, i: u32) -> Self {
+        self.set_word(28, i)
+    }
+
+    /// `ADRS.setTreeIndex(i)`
+    pub const fn set_tree_index(self, i: u32) -> Self {
+        self.set_word(28, i)
+    }
+
+    /// `ADRS.getKeyPairAddress()`
+    pub const fn key_pair_address(&self) -> u32 {
+        self.word(20)
+    }
+
+    /// `ADRS.getTreeIndex()`
+    pub const fn tree_index(&self) -> u32 {
+        self.word(28)
+    }
+}
+
+impl Default for Adrs {
+    fn default() -> Self {
+        Adrs::new()
+    }
+}
+
+/// `PRF(PK.seed, SK.seed, ADRS) = SHAKE256(PK.seed || ADRS || SK.seed, 8n)`
+pub const fn prf<const N: usize>(pk_seed: &[u8; N], sk_seed: &[u8; N], adrs: &Adrs) -> [u8; N] {
+    Shake256::new()
+        .update(pk_seed)
+        .update(&adrs.0)
+        .update(sk_seed)
+        .finalize()
+}
+
+/// `PRF_msg(SK.prf, opt_rand, M) = SHAKE256(SK.prf || opt_rand || M, 8n)`
+pub const fn prf_msg<const N: usize>(sk_prf: &[u8; N], opt_rand: &[u8; N], m: &[u8]) -> [u8; N] {
+    Shake256::new()
+        .update(sk_prf)
+        .update(opt_rand)
+        .update(m)
+        .finalize()
+}
+
+/// `H_msg(R, PK.seed, PK.root, M) = SHAKE256(R || PK.seed || PK.root || M,
+/// 8m)`
+pub const fn h_msg<const N: usize, const M: usize>(
+    r: &[u8; N],
+    pk_seed: &[u8; N],
+    pk_root: &[u8; N],
+    m: &[u8],
+) -> [u8; M] {
+    Shake256::new()
+        .update(r)
+        .update(pk_seed)
+        .update(pk_root)
+        .update(m)
+        .finalize()
+}
+
+/// `F(PK.seed, ADRS, M1) = SHAKE256(PK.seed || ADRS || M1, 8n)`
+pub const fn f<const N: usize>(pk_seed: &[u8; N], adrs: &Adrs, m1: &[u8; N]) -> [u8; N] {
+    Shake256::new()
+        .update(pk_seed)
+        .update(&adrs.0)
+        .update(m1)
+        .finalize()
+}
+
+/// `H(PK.seed, ADRS, M2) = SHAKE256(PK.seed || ADRS || M2, 8n)` where `M2` is
+/// the concatenation of `left` and `right`
+pub const fn h<const N: usize>(
+    pk_seed: &[u8; N],
+    adrs: &Adrs,
+    left: &[u8; N],
+    right: &[u8; N],
+) -> [u8; N] {
+    Shake256::new()
+        .update(pk_seed)
+        .update(&adrs.0)
+        .update(left)
+        .update(right)
+        .finalize()
+}
+
+/// `T_l(PK.seed, ADRS, M_l) = SHAKE256(PK.seed || ADRS || M_l, 8n)` where
+/// `M_l` is the concatenation of the `l` blocks of `m`
+pub const fn t_l<const N: usize>(pk_seed: &[u8; N], adrs: &Adrs, m: &[[u8; N]]) -> [u8; N] {
+    let mut shake = Shake256::new().update(pk_seed).update(&adrs.0);
+    let mut i = 0;
+    while i < m.len() {
+        shake = shake.update(&m[i]);
+        i += 1;
+    }
+    shake.finalize()
+}
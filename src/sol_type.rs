@@ -0,0 +1,305 @@
+//! Mapping of Rust types to Solidity ABI type names
+//!
+//! The type names are computed in constant expressions, so selectors and
+//! event topics can be derived from Rust types instead of hand-written
+//! signatures.
+//!
+//! # Examples
+//!
+//! ```rust
+//! # use keccak_const::address::Address;
+//! # use keccak_const::sol_type::selector_of;
+//! # use keccak_const::sol_type::SolType;
+//! # use keccak_const::sol_type::U256;
+//! const TRANSFER: [u8; 4] = selector_of::<(Address, U256)>("transfer");
+//!
+//! assert_eq!([0xa9, 0x05, 0x9c, 0xbb], TRANSFER);
+//! assert_eq!("(address,uint256)[2]", <[(Address, U256); 2]>::ABI_NAME.as_str());
+//! ```
+
+use crate::address::Address;
+use crate::Keccak256;
+
+/// Capacity of an [`AbiName`]
+pub const MAX_ABI_NAME_LEN: usize = 256;
+
+/// A Solidity type name built in constant expressions
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct AbiName {
+    bytes: [u8; MAX_ABI_NAME_LEN],
+    len: usize,
+}
+
+impl AbiName {
+    /// Constructs an empty name
+    pub const fn new() -> AbiName {
+        AbiName {
+            bytes: [0; MAX_ABI_NAME_LEN],
+            len: 0,
+        }
+    }
+
+    /// Appends bytes
+    pub const fn push_bytes(mut self, bytes: &[u8]) -> Self {
+        assert!(
+            self.len + bytes.len() <= MAX_ABI_NAME_LEN,
+            "ABI type name too long"
+        );
+        let mut i = 0;
+        while i < bytes.len() {
+            self.bytes[self.len] = bytes[i];
+            self.len += 1;
+            i += 1;
+        }
+        self
+    }
+
+    /// Appends another name
+    pub const fn push_name(self, name: &AbiName) -> Self {
+        self.push_bytes(name.as_bytes())
+    }
+
+    /// Appends the decimal representation of `value`
+    pub const fn push_decimal(self, value: usize) -> Self {
+        self.push_bytes(Decimal::new(value as u64).as_bytes())
+    }
+
+    /// Returns the name
+    pub const fn as_bytes(&self) -> &[u8] {
+        self.bytes.split_at(self.len).0
+    }
+
+    /// Returns the name
+    pub const fn as_str(&self) -> &str {
+        match core::str::from_utf8(self.as_bytes()) {
+            Ok(name) => name,
+            Err(_) => unreachable!(),
+        }
+    }
+}
+
+impl Default for AbiName {
+    fn default() -> Self {
+        AbiName::new()
+    }
+}
+
+/// The decimal representation of an integer, as in type names, EIP-191
+/// message lengths and EIP-1191 chain ids
+pub(crate) struct Decimal {
+    digits: [u8; 20],
+    start: usize,
+}
+
+impl Decimal {
+    /// Formats `value`
+    pub(crate) const fn new(value: u64) -> Decimal {
+        let mut digits = [0; 20];
+        let mut start = digits.len();
+        let mut value = value;
+        loop {
+            start -= 1;
+            digits[start] = b'0' + (value % 10) as u8;
+            value /= 10;
+            if value == 0 {
+                break;
+            }
+        }
+        Decimal { digits, start }
+    }
+
+    /// Returns the digits
+    pub(crate) const fn as_bytes(&self) -> &[u8] {
+        self.digits.split_at(self.start).1
+    }
+}
+
+/// A Rust type with a corresponding Solidity ABI type
+pub trait SolType {
+    /// The canonical Solidity type name
+    const ABI_NAME: AbiName;
+}
+
+/// A `uint256` value as 32 big-endian bytes
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct U256(pub [u8; 32]);
+
+impl U256 {
+    /// Converts a `u128`
+    pub const fn from_u128(value: u128) -> U256 {
+        let mut bytes = [0; 32];
+        let value = value.to_be_bytes();
+        let mut i = 0;
+        while i < 16 {
+            bytes[16 + i] = value[i];
+            i += 1;
+        }
+        U256(bytes)
+    }
+
+    /// Adds modulo 2^256
+    pub const fn wrapping_add(&self, other: &U256) -> U256 {
+        let mut sum = [0; 32];
+        let mut carry = 0;
+        let mut i = 32;
+        while i > 0 {
+            i -= 1;
+            let digit = self.0[i] as u16 + other.0[i] as u16 + carry;
+            sum[i] = digit as u8;
+            carry = digit >> 8;
+        }
+        U256(sum)
+    }
+
+    /// Subtracts modulo 2^256
+    pub const fn wrapping_sub(&self, other: &U256) -> U256 {
+        let mut difference = [0; 32];
+        let mut borrow = 0;
+        let mut i = 32;
+        while i > 0 {
+            i -= 1;
+            let digit = self.0[i] as i16 - other.0[i] as i16 - borrow;
+            difference[i] = digit as u8;
+            borrow = (digit < 0) as i16;
+        }
+        U256(difference)
+    }
+
+    /// Computes the bitwise and
+    pub const fn bitand(&self, other: &U256) -> U256 {
+        let mut bits = [0; 32];
+        let mut i = 0;
+        while i < 32 {
+            bits[i] = self.0[i] & other.0[i];
+            i += 1;
+        }
+        U256(bits)
+    }
+}
+
+/// An `int256` value as 32 big-endian two's complement bytes
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct I256(pub [u8; 32]);
+
+/// A `bytesN` value
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct FixedBytes<const N: usize>(pub [u8; N]);
+
+/// A dynamic `bytes` value
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Bytes<'a>(pub &'a [u8]);
+
+macro_rules! sol_type {
+    ($($ty:ty => $name:literal,)*) => {
+        $(
+            impl SolType for $ty {
+                const ABI_NAME: AbiName = AbiName::new().push_bytes($name);
+            }
+        )*
+    };
+}
+
+sol_type!(
+    bool => b"bool",
+    u8 => b"uint8",
+    u16 => b"uint16",
+    u32 => b"uint32",
+    u64 => b"uint64",
+    u128 => b"uint128",
+    i8 => b"int8",
+    i16 => b"int16",
+    i32 => b"int32",
+    i64 => b"int64",
+    i128 => b"int128",
+    U256 => b"uint256",
+    I256 => b"int256",
+    Address => b"address",
+    Bytes<'_> => b"bytes",
+    str => b"string",
+);
+
+impl<const N: usize> SolType for FixedBytes<N> {
+    const ABI_NAME: AbiName = {
+        assert!(1 <= N && N <= 32, "bytesN must be 1 to 32 bytes long");
+        AbiName::new().push_bytes(b"bytes").push_decimal(N)
+    };
+}
+
+impl<T: SolType + ?Sized> SolType for &T {
+    const ABI_NAME: AbiName = T::ABI_NAME;
+}
+
+impl<T: SolType, const N: usize> SolType for [T; N] {
+    const ABI_NAME: AbiName = T::ABI_NAME
+        .push_bytes(b"[")
+        .push_decimal(N)
+        .push_bytes(b"]");
+}
+
+impl<T: SolType> SolType for [T] {
+    const ABI_NAME: AbiName = T::ABI_NAME.push_bytes(b"[]");
+}
+
+#[cfg(feature = "alloc")]
+impl<T: SolType> SolType for alloc::vec::Vec<T> {
+    const ABI_NAME: AbiName = T::ABI_NAME.push_bytes(b"[]");
+}
+
+#[cfg(feature = "alloc")]
+impl SolType for alloc::string::String {
+    const ABI_NAME: AbiName = str::ABI_NAME;
+}
+
+macro_rules! sol_tuple {
+    ($first:ident $(, $rest:ident)*) => {
+        impl<$first: SolType, $($rest: SolType),*> SolType for ($first, $($rest,)*) {
+            const ABI_NAME: AbiName = AbiName::new()
+                .push_bytes(b"(")
+                .push_name(&$first::ABI_NAME)
+                $(.push_bytes(b",").push_name(&$rest::ABI_NAME))*
+                .push_bytes(b")");
+        }
+    };
+}
+
+impl SolType for () {
+    const ABI_NAME: AbiName = AbiName::new().push_bytes(b"()");
+}
+
+sol_tuple!(A);
+sol_tuple!(A, B);
+sol_tuple!(A, B, C);
+sol_tuple!(A, B, C, D);
+sol_tuple!(A, B, C, D, E);
+sol_tuple!(A, B, C, D, E, F);
+sol_tuple!(A, B, C, D, E, F, G);
+sol_tuple!(A, B, C, D, E, F, G, H);
+sol_tuple!(A, B, C, D, E, F, G, H, I);
+sol_tuple!(A, B, C, D, E, F, G, H, I, J);
+sol_tuple!(A, B, C, D, E, F, G, H, I, J, K);
+sol_tuple!(A, B, C, D, E, F, G, H, I, J, K, L);
+
+/// Hashes `name` followed by the ABI name of the parameter tuple `P`
+const fn hash_signature<P: SolType>(name: &str) -> [u8; 32] {
+    let params = P::ABI_NAME;
+    let params = params.as_bytes();
+    assert!(
+        params[0] == b'(' && params[params.len() - 1] == b')',
+        "parameters must be a tuple"
+    );
+    Keccak256::new()
+        .update(name.as_bytes())
+        .update(params)
+        .finalize()
+}
+
+/// Computes the selector of the function `name` with the parameter tuple `P`
+pub const fn selector_of<P: SolType>(name: &str) -> [u8; 4] {
+    let hash = hash_signature::<P>(name);
+    [hash[0], hash[1], hash[2], hash[3]]
+}
+
+/// Computes the topic of the event `name` with the parameter tuple `P`
+pub const fn event_topic_of<P: SolType>(name: &str) -> [u8; 32] {
+    hash_signature::<P>(name)
+}
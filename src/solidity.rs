@@ -0,0 +1,582 @@
+//! Solidity function selectors, error selectors, event topics and interface
+//! identifiers
+//!
+//! Signatures must be in the canonical form used by the ABI: the function name
+//! followed by the comma separated parameter types, without spaces or
+//! parameter names, and with aliases such as `uint` expanded to `uint256`.
+//! Invalid signatures panic, which fails compilation when evaluated in a
+//! constant. Human-readable declarations can be converted to the canonical
+//! form with [`Signature::parse`].
+//!
+//! # Examples
+//!
+//! ```rust
+//! # use keccak_const::solidity;
+//! const TRANSFER: [u8; 4] = solidity::function_selector("transfer(address,uint256)");
+//!
+//! assert_eq!([0xa9, 0x05, 0x9c, 0xbb], TRANSFER);
+//! ```
+//!
+//! ```rust
+//! # use keccak_const::address::Address;
+//! # use keccak_const::solidity;
+//! const TRANSFER_TOPICS: [[u8; 32]; 3] = [
+//!     solidity::event_topic("Transfer(address,address,uint256)"),
+//!     solidity::indexed_address(&Address([0x11; 20])),
+//!     solidity::indexed_address(&Address([0x22; 20])),
+//! ];
+//! ```
+//!
+//! ```rust
+//! # use keccak_const::solidity::Signature;
+//! const TRANSFER: Signature<64> =
+//!     Signature::parse("function transfer(address to, uint amount) external returns (bool)");
+//!
+//! assert_eq!("transfer(address,uint256)", TRANSFER.as_str());
+//! assert_eq!([0xa9, 0x05, 0x9c, 0xbb], TRANSFER.selector());
+//! ```
+//!
+//! ```compile_fail
+//! # use keccak_const::solidity;
+//! const TRANSFER: [u8; 4] = solidity::function_selector("transfer(address to, uint amount)");
+//! ```
+
+use crate::abi::address_word;
+use crate::address::Address;
+use crate::sol_type::I256;
+use crate::sol_type::U256;
+use crate::Keccak256;
+
+/// Computes the 4-byte selector of a canonical function signature
+pub const fn function_selector(signature: &str) -> [u8; 4] {
+    let signature = signature.as_bytes();
+    check_signature(signature);
+    let hash = Keccak256::new().update(signature).finalize();
+    [hash[0], hash[1], hash[2], hash[3]]
+}
+
+/// Computes the selector of a canonical function signature as a big-endian
+/// `u32`
+pub const fn function_selector_u32(signature: &str) -> u32 {
+    u32::from_be_bytes(function_selector(signature))
+}
+
+/// Computes the 4-byte selector of a canonical custom error signature
+pub const fn error_selector(signature: &str) -> [u8; 4] {
+    function_selector(signature)
+}
+
+/// Computes the topic of a canonical event signature
+pub const fn event_topic(signature: &str) -> [u8; 32] {
+    let signature = signature.as_bytes();
+    check_signature(signature);
+    Keccak256::new().update(signature).finalize()
+}
+
+/// Computes the ERC-165 interface identifier of the canonical function
+/// signatures of an interface
+///
+/// The identifier is the XOR of the function selectors. Duplicate signatures
+/// would cancel out and panic instead.
+pub const fn interface_id(signatures: &[&str]) -> [u8; 4] {
+    let mut id = 0;
+    let mut i = 0;
+    while i < signatures.len() {
+        let signature = signatures[i].as_bytes();
+        let mut j = 0;
+        while j < i {
+            assert!(
+                !eq_range(signature, 0, signature.len(), signatures[j].as_bytes()),
+                "duplicate signature in interface"
+            );
+            j += 1;
+        }
+        id ^= function_selector_u32(signatures[i]);
+        i += 1;
+    }
+    id.to_be_bytes()
+}
+
+/// Computes the ERC-1820 interface hash of an interface name, e.g.
+/// `ERC777TokensRecipient`
+///
+/// Interfaces identified by an ERC-165 interface identifier are registered
+/// with the identifier left-aligned in 32 bytes instead.
+pub const fn erc1820_interface_hash(name: &str) -> [u8; 32] {
+    Keccak256::new().update(name.as_bytes()).finalize()
+}
+
+/// Topic of an indexed `address` argument
+pub const fn indexed_address(address: &Address) -> [u8; 32] {
+    address_word(address)
+}
+
+/// Topic of an indexed `uintN` argument of at most 128 bits
+///
+/// Wider arguments are built with [`indexed_u256`].
+pub const fn indexed_uint(value: u128) -> [u8; 32] {
+    let bytes = value.to_be_bytes();
+    let mut topic = [0; 32];
+    let mut i = 0;
+    while i < bytes.len() {
+        topic[16 + i] = bytes[i];
+        i += 1;
+    }
+    topic
+}
+
+/// Topic of an indexed `intN` argument of at most 128 bits, sign-extended to
+/// 32 bytes
+///
+/// Wider arguments are built with [`indexed_i256`].
+pub const fn indexed_int(value: i128) -> [u8; 32] {
+    let bytes = value.to_be_bytes();
+    let mut topic = if value < 0 { [0xff; 32] } else { [0; 32] };
+    let mut i = 0;
+    while i < bytes.len() {
+        topic[16 + i] = bytes[i];
+        i += 1;
+    }
+    topic
+}
+
+/// Topic of an indexed `uint256` argument, its 32 big-endian bytes
+pub const fn indexed_u256(value: &U256) -> [u8; 32] {
+    value.0
+}
+
+/// Topic of an indexed `int256` argument, its 32 big-endian two's complement
+/// bytes
+pub const fn indexed_i256(value: &I256) -> [u8; 32] {
+    value.0
+}
+
+/// Topic of an indexed `bool` argument
+pub const fn indexed_bool(value: bool) -> [u8; 32] {
+    let mut topic = [0; 32];
+    topic[31] = value as u8;
+    topic
+}
+
+/// Topic of an indexed `bytesN` argument, right-padded to 32 bytes
+pub const fn indexed_bytes_n(value: &[u8]) -> [u8; 32] {
+    assert!(value.len() <= 32, "bytesN is at most 32 bytes long");
+    let mut topic = [0; 32];
+    let mut i = 0;
+    while i < value.len() {
+        topic[i] = value[i];
+        i += 1;
+    }
+    topic
+}
+
+/// Topic of an indexed `bytes` argument, its Keccak-256 hash
+pub const fn indexed_bytes(value: &[u8]) -> [u8; 32] {
+    Keccak256::new().update(value).finalize()
+}
+
+/// Topic of an indexed `string` argument, its Keccak-256 hash
+pub const fn indexed_string(value: &str) -> [u8; 32] {
+    indexed_bytes(value.as_bytes())
+}
+
+/// Topic of an indexed array of value types, the Keccak-256 hash of its
+/// elements each padded to 32 bytes
+pub const fn indexed_array(elements: &[[u8; 32]]) -> [u8; 32] {
+    let mut keccak = Keccak256::new();
+    let mut i = 0;
+    while i < elements.len() {
+        keccak = keccak.update(&elements[i]);
+        i += 1;
+    }
+    keccak.finalize()
+}
+
+/// Returns the byte at `i`, or zero past the end of `s`
+const fn byte_at(s: &[u8], i: usize) -> u8 {
+    if i < s.len() {
+        s[i]
+    } else {
+        0
+    }
+}
+
+const fn is_identifier_start(c: u8) -> bool {
+    c.is_ascii_alphabetic() || c == b'_' || c == b'$'
+}
+
+const fn is_identifier_char(c: u8) -> bool {
+    is_identifier_start(c) || c.is_ascii_digit()
+}
+
+/// Compares `s[start..end]` with `name`
+const fn eq_range(s: &[u8], start: usize, end: usize, name: &[u8]) -> bool {
+    if end - start != name.len() {
+        return false;
+    }
+    let mut i = 0;
+    while i < name.len() {
+        if s[start + i] != name[i] {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+/// Parses a decimal number without leading zeros in `s[start..end]`
+const fn parse_number(s: &[u8], start: usize, end: usize) -> Option<usize> {
+    if start == end || (s[start] == b'0' && end - start > 1) {
+        return None;
+    }
+    let mut value = 0usize;
+    let mut i = start;
+    while i < end {
+        if !s[i].is_ascii_digit() || value > 1_000_000 {
+            return None;
+        }
+        value = 10 * value + (s[i] - b'0') as usize;
+        i += 1;
+    }
+    Some(value)
+}
+
+/// Returns whether `s[start..end]` is `prefix` followed by a multiple of
+/// `step` in `min..=max`
+const fn is_sized(
+    s: &[u8],
+    start: usize,
+    end: usize,
+    prefix: &[u8],
+    min: usize,
+    max: usize,
+    step: usize,
+) -> bool {
+    if end - start <= prefix.len() || !eq_range(s, start, start + prefix.len(), prefix) {
+        return false;
+    }
+    match parse_number(s, start + prefix.len(), end) {
+        Some(n) => min <= n && n <= max && n % step == 0,
+        None => false,
+    }
+}
+
+/// Returns whether `s[start..end]` is a canonical `fixedMxN` or `ufixedMxN`
+const fn is_fixed(s: &[u8], start: usize, end: usize) -> bool {
+    let prefix_len = if byte_at(s, start) == b'u' { 6 } else { 5 };
+    if end - start <= prefix_len
+        || !(eq_range(s, start, start + prefix_len, b"fixed")
+            || eq_range(s, start, start + prefix_len, b"ufixed"))
+    {
+        return false;
+    }
+    let mut x = start + prefix_len;
+    while x < end && s[x] != b'x' {
+        x += 1;
+    }
+    if x == end {
+        return false;
+    }
+    match (
+        parse_number(s, start + prefix_len, x),
+        parse_number(s, x + 1, end),
+    ) {
+        (Some(m), Some(n)) => 8 <= m && m <= 256 && m % 8 == 0 && n <= 80,
+        _ => false,
+    }
+}
+
+/// Checks an elementary type starting at `start` and returns its end
+const fn check_elementary_type(s: &[u8], start: usize) -> usize {
+    let mut end = start;
+    while end < s.len() && (s[end].is_ascii_lowercase() || s[end].is_ascii_digit()) {
+        end += 1;
+    }
+    if eq_range(s, start, end, b"uint") {
+        panic!("invalid signature: `uint` must be written as `uint256`");
+    }
+    if eq_range(s, start, end, b"int") {
+        panic!("invalid signature: `int` must be written as `int256`");
+    }
+    if eq_range(s, start, end, b"byte") {
+        panic!("invalid signature: `byte` must be written as `bytes1`");
+    }
+    if eq_range(s, start, end, b"fixed") || eq_range(s, start, end, b"ufixed") {
+        panic!("invalid signature: `fixed` and `ufixed` must include their size");
+    }
+    if !(eq_range(s, start, end, b"address")
+        || eq_range(s, start, end, b"bool")
+        || eq_range(s, start, end, b"string")
+        || eq_range(s, start, end, b"bytes")
+        || eq_range(s, start, end, b"function")
+        || is_sized(s, start, end, b"uint", 8, 256, 8)
+        || is_sized(s, start, end, b"int", 8, 256, 8)
+        || is_sized(s, start, end, b"bytes", 1, 32, 1)
+        || is_fixed(s, start, end))
+    {
+        panic!("invalid signature: unknown type");
+    }
+    end
+}
+
+/// Checks a type starting at `i` and returns its end
+pub(crate) const fn check_type(s: &[u8], i: usize) -> usize {
+    let mut i = if byte_at(s, i) == b'(' {
+        check_tuple(s, i)
+    } else {
+        check_elementary_type(s, i)
+    };
+    while byte_at(s, i) == b'[' {
+        let start = i + 1;
+        i = start;
+        while byte_at(s, i).is_ascii_digit() {
+            i += 1;
+        }
+        if i > start && !matches!(parse_number(s, start, i), Some(1..)) {
+            panic!("invalid signature: invalid array length");
+        }
+        if byte_at(s, i) != b']' {
+            panic!("invalid signature: expected `]`");
+        }
+        i += 1;
+    }
+    i
+}
+
+/// Checks a parenthesized list of types starting at `i` and returns its end
+const fn check_tuple(s: &[u8], mut i: usize) -> usize {
+    i += 1;
+    if byte_at(s, i) == b')' {
+        return i + 1;
+    }
+    loop {
+        i = check_type(s, i);
+        match byte_at(s, i) {
+            b',' => i += 1,
+            b')' => return i + 1,
+            _ => panic!("invalid signature: expected `,` or `)`"),
+        }
+    }
+}
+
+/// Panics unless `s` is a canonical signature `name(type,...)`
+const fn check_signature(s: &[u8]) {
+    let mut i = 0;
+    while i < s.len() {
+        if s[i].is_ascii_whitespace() {
+            panic!("invalid signature: must not contain whitespace or parameter names");
+        }
+        i += 1;
+    }
+    if !is_identifier_start(byte_at(s, 0)) {
+        panic!("invalid signature: expected a name");
+    }
+    let mut i = 1;
+    while is_identifier_char(byte_at(s, i)) {
+        i += 1;
+    }
+    if byte_at(s, i) != b'(' {
+        panic!("invalid signature: expected `(`");
+    }
+    if check_tuple(s, i) != s.len() {
+        panic!("invalid signature: unexpected characters after `)`");
+    }
+}
+
+/// A canonical signature normalized from a human-readable declaration,
+/// stored in a buffer of `N` bytes
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Signature<const N: usize> {
+    bytes: [u8; N],
+    len: usize,
+}
+
+impl<const N: usize> Signature<N> {
+    /// Normalizes a function, event or error declaration
+    ///
+    /// The leading `function`, `event` or `error` keyword is optional.
+    /// Parameter names, the `memory`, `calldata`, `storage`, `indexed` and
+    /// `payable` keywords and the `tuple` prefix are removed, and the aliases
+    /// `uint`, `int`, `byte`, `fixed` and `ufixed` are expanded. Anything
+    /// following the parameter list, such as visibility, mutability or return
+    /// types, is ignored.
+    pub const fn parse(declaration: &str) -> Self {
+        let s = declaration.as_bytes();
+        let mut signature = Signature {
+            bytes: [0; N],
+            len: 0,
+        };
+
+        let mut i = skip_whitespace(s, 0);
+        let mut end = identifier_end(s, i);
+        if eq_range(s, i, end, b"function")
+            || eq_range(s, i, end, b"event")
+            || eq_range(s, i, end, b"error")
+        {
+            i = skip_whitespace(s, end);
+            end = identifier_end(s, i);
+        }
+        if i == end || !is_identifier_start(s[i]) {
+            panic!("invalid declaration: expected a name");
+        }
+        while i < end {
+            signature = signature.push(s[i]);
+            i += 1;
+        }
+        i = skip_whitespace(s, i);
+        if byte_at(s, i) != b'(' {
+            panic!("invalid declaration: expected `(`");
+        }
+        (signature, _) = signature.parse_parameters(s, i);
+
+        check_signature(signature.as_bytes());
+        signature
+    }
+
+    /// Returns the canonical signature
+    pub const fn as_bytes(&self) -> &[u8] {
+        self.bytes.split_at(self.len).0
+    }
+
+    /// Returns the canonical signature
+    pub const fn as_str(&self) -> &str {
+        match core::str::from_utf8(self.as_bytes()) {
+            Ok(signature) => signature,
+            Err(_) => unreachable!(),
+        }
+    }
+
+    /// Computes the 4-byte function or error selector
+    pub const fn selector(&self) -> [u8; 4] {
+        function_selector(self.as_str())
+    }
+
+    /// Computes the event topic
+    pub const fn topic(&self) -> [u8; 32] {
+        event_topic(self.as_str())
+    }
+
+    const fn push(mut self, c: u8) -> Self {
+        if self.len == N {
+            panic!("invalid declaration: canonical signature exceeds the buffer");
+        }
+        self.bytes[self.len] = c;
+        self.len += 1;
+        self
+    }
+
+    const fn push_str(mut self, s: &[u8]) -> Self {
+        let mut i = 0;
+        while i < s.len() {
+            self = self.push(s[i]);
+            i += 1;
+        }
+        self
+    }
+
+    /// Parses a parenthesized parameter list at `i`
+    const fn parse_parameters(mut self, s: &[u8], i: usize) -> (Self, usize) {
+        self = self.push(b'(');
+        let mut i = skip_whitespace(s, i + 1);
+        if byte_at(s, i) == b')' {
+            return (self.push(b')'), i + 1);
+        }
+        loop {
+            (self, i) = self.parse_parameter(s, i);
+            match byte_at(s, i) {
+                b',' => {
+                    self = self.push(b',');
+                    i = skip_whitespace(s, i + 1);
+                }
+                b')' => return (self.push(b')'), i + 1),
+                _ => panic!("invalid declaration: expected `,` or `)`"),
+            }
+        }
+    }
+
+    /// Parses a type followed by optional keywords and name at `i`
+    const fn parse_parameter(mut self, s: &[u8], i: usize) -> (Self, usize) {
+        let mut i = i;
+        let end = identifier_end(s, i);
+        if byte_at(s, i) == b'(' {
+            (self, i) = self.parse_parameters(s, i);
+        } else if eq_range(s, i, end, b"tuple") && byte_at(s, skip_whitespace(s, end)) == b'(' {
+            (self, i) = self.parse_parameters(s, skip_whitespace(s, end));
+        } else if i == end {
+            panic!("invalid declaration: expected a type");
+        } else {
+            self = if eq_range(s, i, end, b"uint") {
+                self.push_str(b"uint256")
+            } else if eq_range(s, i, end, b"int") {
+                self.push_str(b"int256")
+            } else if eq_range(s, i, end, b"byte") {
+                self.push_str(b"bytes1")
+            } else if eq_range(s, i, end, b"fixed") {
+                self.push_str(b"fixed128x18")
+            } else if eq_range(s, i, end, b"ufixed") {
+                self.push_str(b"ufixed128x18")
+            } else {
+                self.push_str(s.split_at(end).0.split_at(i).1)
+            };
+            i = end;
+        }
+
+        // array suffixes
+        i = skip_whitespace(s, i);
+        while byte_at(s, i) == b'[' {
+            self = self.push(b'[');
+            i = skip_whitespace(s, i + 1);
+            while byte_at(s, i).is_ascii_digit() {
+                self = self.push(s[i]);
+                i += 1;
+            }
+            i = skip_whitespace(s, i);
+            if byte_at(s, i) != b']' {
+                panic!("invalid declaration: expected `]`");
+            }
+            self = self.push(b']');
+            i = skip_whitespace(s, i + 1);
+        }
+
+        // keywords and the parameter name
+        let mut named = false;
+        loop {
+            let end = identifier_end(s, i);
+            if i == end {
+                break;
+            }
+            let keyword = eq_range(s, i, end, b"memory")
+                || eq_range(s, i, end, b"calldata")
+                || eq_range(s, i, end, b"storage")
+                || eq_range(s, i, end, b"indexed")
+                || eq_range(s, i, end, b"payable");
+            if !keyword {
+                if named {
+                    panic!("invalid declaration: unexpected identifier after parameter name");
+                }
+                named = true;
+            }
+            i = skip_whitespace(s, end);
+        }
+        (self, i)
+    }
+}
+
+const fn skip_whitespace(s: &[u8], mut i: usize) -> usize {
+    while byte_at(s, i).is_ascii_whitespace() {
+        i += 1;
+    }
+    i
+}
+
+/// Returns the end of the identifier starting at `i`, or `i` if there is none
+const fn identifier_end(s: &[u8], i: usize) -> usize {
+    if !is_identifier_start(byte_at(s, i)) {
+        return i;
+    }
+    let mut end = i + 1;
+    while is_identifier_char(byte_at(s, end)) {
+        end += 1;
+    }
+    end
+}
@@ -0,0 +1,125 @@
+//! Solidity storage slots
+//!
+//! Locations follow the storage layout of solc. State variables are numbered
+//! from slot 0 in declaration order, with value types smaller than 32 bytes
+//! packed together; the data of mappings, dynamic arrays and long `string` or
+//! `bytes` values is at slots derived from the slot of the variable with
+//! Keccak-256. Offsets are in bytes from the lower-order end of a slot, as in
+//! the `storageLayout` output of solc.
+//!
+//! # Examples
+//!
+//! ```rust
+//! # use keccak_const::address::Address;
+//! # use keccak_const::sol_type::U256;
+//! # use keccak_const::solidity;
+//! # use keccak_const::storage;
+//! // mapping(address => mapping(address => uint256)) allowance; at slot 1
+//! const ALLOWANCE: U256 = storage::nested_mapping_slot(
+//!     &[
+//!         solidity::indexed_address(&Address([0x11; 20])),
+//!         solidity::indexed_address(&Address([0x22; 20])),
+//!     ],
+//!     &U256::from_u128(1),
+//! );
+//! ```
+//!
+//! ```rust
+//! # use keccak_const::sol_type::U256;
+//! # use keccak_const::storage;
+//! const IMPLEMENTATION_SLOT: U256 = storage::eip1967_slot("eip1967.proxy.implementation");
+//! const MAIN_STORAGE_LOCATION: U256 = storage::erc7201_slot("example.main");
+//! ```
+
+use crate::sol_type::U256;
+use crate::Keccak256;
+
+/// Computes the slot of the value of a key of a value type in a mapping at
+/// `slot`
+///
+/// See [`mapping_slot_bytes`] for how keys are padded.
+pub const fn mapping_slot(key: &[u8; 32], slot: &U256) -> U256 {
+    mapping_slot_bytes(key, slot)
+}
+
+/// Computes the slot of the value of a key in a mapping at `slot`,
+/// `keccak256(key || slot)`
+///
+/// Keys of value types are padded to 32 bytes as in the ABI encoding, for
+/// example with [`indexed_address`](crate::solidity::indexed_address), while
+/// `string` and `bytes` keys are hashed without padding.
+pub const fn mapping_slot_bytes(key: &[u8], slot: &U256) -> U256 {
+    U256(Keccak256::new().update(key).update(&slot.0).finalize())
+}
+
+/// Computes the slot of the value of nested mappings at `slot`, with the keys
+/// of the outermost mapping first
+pub const fn nested_mapping_slot(keys: &[[u8; 32]], slot: &U256) -> U256 {
+    let mut slot = *slot;
+    let mut i = 0;
+    while i < keys.len() {
+        slot = mapping_slot(&keys[i], &slot);
+        i += 1;
+    }
+    slot
+}
+
+/// Computes the first slot of the elements of a dynamic array at `slot`,
+/// which holds the length
+pub const fn dynamic_array_data_slot(slot: &U256) -> U256 {
+    U256(Keccak256::new().update(&slot.0).finalize())
+}
+
+/// Computes the slot and offset of an element of an array whose elements start
+/// at `start`
+///
+/// `start` is the slot of a static array or [`dynamic_array_data_slot`] of a
+/// dynamic array. Elements of value types are packed, as many as fit in a
+/// slot; structs and static arrays take whole slots, `element_size` being
+/// their number of slots times 32.
+#[allow(clippy::manual_is_multiple_of)]
+pub const fn array_element_slot(start: &U256, index: u64, element_size: usize) -> (U256, usize) {
+    assert!(
+        element_size > 0 && (element_size <= 32 || element_size % 32 == 0),
+        "element size must be at most 32 bytes or a multiple of 32"
+    );
+    if element_size <= 32 {
+        let per_slot = (32 / element_size) as u64;
+        let slot = start.wrapping_add(&U256::from_u128((index / per_slot) as u128));
+        (slot, (index % per_slot) as usize * element_size)
+    } else {
+        let slots = (element_size / 32) as u128;
+        (
+            start.wrapping_add(&U256::from_u128(index as u128 * slots)),
+            0,
+        )
+    }
+}
+
+/// Computes the slot of a chunk of 32 bytes of a long `string` or `bytes`
+/// value at `slot`
+///
+/// Values of at least 32 bytes keep `2 * len + 1` at `slot` and their data
+/// left-aligned from [`dynamic_array_data_slot`]; shorter values are stored in
+/// the higher-order bytes of `slot` with `2 * len` in its lowest byte.
+pub const fn long_bytes_data_slot(slot: &U256, chunk: u64) -> U256 {
+    dynamic_array_data_slot(slot).wrapping_add(&U256::from_u128(chunk as u128))
+}
+
+/// Computes the ERC-7201 storage location of a namespace,
+/// `keccak256(abi.encode(uint256(keccak256(id)) - 1)) &
+/// ~bytes32(uint256(0xff))`
+///
+/// This is the formula of the `erc7201` namespace annotation,
+/// `@custom:storage-location erc7201:<id>`.
+pub const fn erc7201_slot(id: &str) -> U256 {
+    let mut mask = [0xff; 32];
+    mask[31] = 0;
+    let hash = Keccak256::new().update(&eip1967_slot(id).0).finalize();
+    U256(hash).bitand(&U256(mask))
+}
+
+/// Computes an EIP-1967 proxy slot, `uint256(keccak256(id)) - 1`
+pub const fn eip1967_slot(id: &str) -> U256 {
+    U256(Keccak256::new().update(id.as_bytes()).finalize()).wrapping_sub(&U256::from_u128(1))
+}
@@ -0,0 +1,139 @@
+//! Selectors of Arbitrum Stylus methods
+//!
+//! Stylus exposes Rust methods under the camelCase version of their
+//! snake_case names, so `balance_of` is called as `balanceOf`. Methods whose
+//! Solidity name does not follow this rule, such as `tokenURI`, override it
+//! with a `#[selector(name = "...")]` attribute, which the
+//! [`stylus_selector!`](crate::stylus_selector) macro accepts as well.
+//!
+//! # Examples
+//!
+//! ```rust
+//! # use keccak_const::stylus;
+//! # use keccak_const::stylus_selector;
+//! const BALANCE_OF: [u8; 4] = stylus::stylus_selector("balance_of", &["address"]);
+//! const TOKEN_URI: [u8; 4] = stylus_selector!(#[selector(name = "tokenURI")] token_uri("uint256"));
+//!
+//! assert_eq!([0x70, 0xa0, 0x82, 0x31], BALANCE_OF);
+//! assert_eq!([0xc8, 0x7b, 0x56, 0xdd], TOKEN_URI);
+//! ```
+
+use crate::solidity::check_type;
+use crate::Keccak256;
+
+/// Computes the selector of a Stylus method from its snake_case Rust name and
+/// its canonical Solidity parameter types
+pub const fn stylus_selector(name: &str, params: &[&str]) -> [u8; 4] {
+    let name = name.as_bytes();
+    let mut keccak = Keccak256::new();
+    let mut upper = false;
+    let mut first = true;
+    let mut i = 0;
+    while i < name.len() {
+        let c = name[i];
+        if c == b'_' {
+            upper = !first;
+        } else if c.is_ascii_alphanumeric() {
+            let c = if upper {
+                c.to_ascii_uppercase()
+            } else {
+                c.to_ascii_lowercase()
+            };
+            keccak = keccak.update(&[c]);
+            upper = false;
+            first = false;
+        } else {
+            panic!("invalid method name");
+        }
+        i += 1;
+    }
+    if first {
+        panic!("invalid method name");
+    }
+    hash_params(keccak, params)
+}
+
+/// Computes the selector of a method with an overridden Solidity name and its
+/// canonical Solidity parameter types
+pub const fn named_selector(name: &str, params: &[&str]) -> [u8; 4] {
+    let name = name.as_bytes();
+    let mut i = 0;
+    while i < name.len() {
+        if !(name[i].is_ascii_alphanumeric() || name[i] == b'_' || name[i] == b'$') {
+            panic!("invalid method name");
+        }
+        i += 1;
+    }
+    if name.is_empty() || name[0].is_ascii_digit() {
+        panic!("invalid method name");
+    }
+    hash_params(Keccak256::new().update(name), params)
+}
+
+/// Converts a snake_case name to camelCase
+///
+/// `N` must be equal to the length of the converted name.
+pub const fn to_camel_case<const N: usize>(name: &str) -> [u8; N] {
+    let name = name.as_bytes();
+    let mut output = [0; N];
+    let mut len = 0;
+    let mut upper = false;
+    let mut i = 0;
+    while i < name.len() {
+        if name[i] == b'_' {
+            upper = len > 0;
+        } else {
+            assert!(len < N, "invalid camelCase length");
+            output[len] = if upper {
+                name[i].to_ascii_uppercase()
+            } else {
+                name[i].to_ascii_lowercase()
+            };
+            len += 1;
+            upper = false;
+        }
+        i += 1;
+    }
+    assert!(len == N, "invalid camelCase length");
+    output
+}
+
+/// Absorbs the parenthesized parameter list and finalizes the selector
+const fn hash_params(mut keccak: Keccak256, params: &[&str]) -> [u8; 4] {
+    keccak = keccak.update(b"(");
+    let mut i = 0;
+    while i < params.len() {
+        let param = params[i].as_bytes();
+        if param.is_empty() || check_type(param, 0) != param.len() {
+            panic!("invalid signature: parameter is not a canonical type");
+        }
+        if i > 0 {
+            keccak = keccak.update(b",");
+        }
+        keccak = keccak.update(param);
+        i += 1;
+    }
+    let hash = keccak.update(b")").finalize();
+    [hash[0], hash[1], hash[2], hash[3]]
+}
+
+/// Computes the selector of a Stylus method, honoring a
+/// `#[selector(name = "...")]` override
+///
+/// # Examples
+///
+/// ```rust
+/// # use keccak_const::stylus_selector;
+/// const TRANSFER_FROM: [u8; 4] = stylus_selector!(transfer_from("address", "address", "uint256"));
+///
+/// assert_eq!([0x23, 0xb8, 0x72, 0xdd], TRANSFER_FROM);
+/// ```
+#[macro_export]
+macro_rules! stylus_selector {
+    (#[selector(name = $name:literal)] $method:ident($($param:literal),* $(,)?)) => {
+        $crate::stylus::named_selector($name, &[$($param),*])
+    };
+    ($method:ident($($param:literal),* $(,)?)) => {
+        $crate::stylus::stylus_selector(stringify!($method), &[$($param),*])
+    };
+}
@@ -0,0 +1,368 @@
+//! Expected encodings follow the Solidity ABI specification, including its
+//! examples for `abi.encode` and `abi.encodePacked`
+
+mod common;
+
+use keccak_const::abi::Encode;
+use keccak_const::abi::EncodePacked;
+use keccak_const::address::Address;
+use keccak_const::Keccak256;
+
+fn keccak256(hex: &str) -> [u8; 32] {
+    Keccak256::new().update(&common::hex_bytes(hex)).finalize()
+}
+
+#[test]
+fn encode_specification_examples() {
+    const BAZ: [u8; 68] = Encode::with_signature("baz(uint32,bool)")
+        .push_uint::<32>(69)
+        .push_bool(true)
+        .finalize();
+    const BAR: [u8; 68] = Encode::with_signature("bar(bytes3[2])")
+        .push_bytes_n_array(&[b"abc", b"def"])
+        .finalize();
+
+    assert_eq!(
+        common::hex_bytes(concat!(
+            "cdcd77c0",
+            "0000000000000000000000000000000000000000000000000000000000000045",
+            "0000000000000000000000000000000000000000000000000000000000000001",
+        )),
+        BAZ
+    );
+    assert_eq!(
+        common::hex_bytes(concat!(
+            "fce353f6",
+            "6162630000000000000000000000000000000000000000000000000000000000",
+            "6465660000000000000000000000000000000000000000000000000000000000",
+        )),
+        BAR
+    );
+}
+
+#[test]
+fn encode_transfer() {
+    let mut amount = [0; 32];
+    amount[0] = 0x80;
+
+    assert_eq!(
+        common::hex_bytes(concat!(
+            "a9059cbb",
+            "000000000000000000000000d8da6bf26964af9d7eed9e03e53415d37aa96045",
+            "8000000000000000000000000000000000000000000000000000000000000000",
+        )),
+        Encode::<68>::with_selector([0xa9, 0x05, 0x9c, 0xbb])
+            .push_address(&Address([
+                0xd8, 0xda, 0x6b, 0xf2, 0x69, 0x64, 0xaf, 0x9d, 0x7e, 0xed, 0x9e, 0x03, 0xe5, 0x34,
+                0x15, 0xd3, 0x7a, 0xa9, 0x60, 0x45,
+            ]))
+            .push_word(&amount)
+            .finalize()
+    );
+}
+
+#[test]
+fn encode_value_types() {
+    const ENCODED: [u8; 320] = Encode::new()
+        .push_uint::<8>(0xff)
+        .push_uint::<256>(u128::MAX)
+        .push_int::<8>(-1)
+        .push_int::<128>(i128::MIN)
+        .push_int::<256>(1)
+        .push_bool(false)
+        .push_address(&Address([0x11; 20]))
+        .push_bytes_n(&[0x42])
+        .push_bytes_n(&[0xaa; 32])
+        .push_word(&[0x55; 32])
+        .finalize();
+
+    assert_eq!(
+        common::hex_bytes(concat!(
+            "00000000000000000000000000000000000000000000000000000000000000ff",
+            "00000000000000000000000000000000ffffffffffffffffffffffffffffffff",
+            "ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff",
+            "ffffffffffffffffffffffffffffffff80000000000000000000000000000000",
+            "0000000000000000000000000000000000000000000000000000000000000001",
+            "0000000000000000000000000000000000000000000000000000000000000000",
+            "0000000000000000000000001111111111111111111111111111111111111111",
+            "4200000000000000000000000000000000000000000000000000000000000000",
+            "aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa",
+            "5555555555555555555555555555555555555555555555555555555555555555",
+        )),
+        ENCODED
+    );
+}
+
+#[test]
+fn encode_nested_static_tuples() {
+    // f((uint8,(bool,address)),int256[2],int24)
+    const CALLDATA: [u8; 196] = Encode::with_signature("f((uint8,(bool,address)),int256[2],int24)")
+        .push_uint::<8>(7)
+        .push_bool(true)
+        .push_address(&Address([0x22; 20]))
+        .push_int_array::<256>(&[-2, 3])
+        .push_int::<24>(-8388608)
+        .finalize();
+
+    assert_eq!(
+        common::hex_bytes(concat!(
+            "34abdec2",
+            "0000000000000000000000000000000000000000000000000000000000000007",
+            "0000000000000000000000000000000000000000000000000000000000000001",
+            "0000000000000000000000002222222222222222222222222222222222222222",
+            "fffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffe",
+            "0000000000000000000000000000000000000000000000000000000000000003",
+            "ffffffffffffffffffffffffffffffffffffffffffffffffffffffffff800000",
+        )),
+        CALLDATA
+    );
+}
+
+#[test]
+fn encode_empty() {
+    assert_eq!([0; 0], Encode::<0>::new().finalize());
+    assert_eq!(
+        [0x18, 0x16, 0x0d, 0xdd],
+        Encode::<4>::with_signature("totalSupply()").finalize()
+    );
+}
+
+#[test]
+#[should_panic(expected = "encoding exceeds LEN")]
+fn encode_too_long() {
+    Encode::<32>::new().push_bool(true).push_bool(false);
+}
+
+#[test]
+#[should_panic(expected = "encoding is shorter than LEN")]
+fn encode_too_short() {
+    Encode::<64>::new().push_bool(true).finalize();
+}
+
+#[test]
+#[should_panic(expected = "LEN must be 4 plus a multiple of 32")]
+fn encode_invalid_calldata_length() {
+    Encode::<64>::with_selector([0; 4]);
+}
+
+#[test]
+fn packed_specification_example() {
+    const HASH: [u8; 32] = EncodePacked::new()
+        .push_int::<16>(-1)
+        .push_bytes_n(&[0x42])
+        .push_uint::<16>(0x03)
+        .push_string("Hello, world!")
+        .finalize();
+
+    assert_eq!(
+        [
+            0xa6, 0x1e, 0xca, 0xcd, 0x5d, 0xe1, 0x49, 0x0d, 0xcd, 0x3f, 0x7d, 0xad, 0x8f, 0x51,
+            0x7c, 0xb3, 0x83, 0xf0, 0x0d, 0x68, 0x39, 0x20, 0x7a, 0x7d, 0x85, 0x87, 0xde, 0xd6,
+            0x96, 0x5e, 0x78, 0x89,
+        ],
+        HASH
+    );
+    assert_eq!(keccak256("ffff42000348656c6c6f2c20776f726c6421"), HASH);
+}
+
+#[test]
+fn commitment() {
+    let mut amount = [0; 32];
+    amount[30] = 0x03;
+    amount[31] = 0xe8;
+    let hash = EncodePacked::new()
+        .push_address(&Address([0x11; 20]))
+        .push_word(&amount)
+        .push_word(&[0xab; 32])
+        .finalize();
+
+    assert_eq!(
+        keccak256(concat!(
+            "1111111111111111111111111111111111111111",
+            "00000000000000000000000000000000000000000000000000000000000003e8",
+            "abababababababababababababababababababababababababababababababab",
+        )),
+        hash
+    );
+}
+
+#[test]
+fn unsigned_integers() {
+    for (hash, expected) in [
+        (EncodePacked::new().push_uint::<8>(0).finalize(), "00"),
+        (EncodePacked::new().push_uint::<8>(0xff).finalize(), "ff"),
+        (
+            EncodePacked::new().push_uint::<24>(0x010203).finalize(),
+            "010203",
+        ),
+        (
+            EncodePacked::new().push_uint::<32>(0xdeadbeef).finalize(),
+            "deadbeef",
+        ),
+        (
+            EncodePacked::new().push_uint::<64>(1).finalize(),
+            "0000000000000001",
+        ),
+        (
+            EncodePacked::new().push_uint::<128>(u128::MAX).finalize(),
+            "ffffffffffffffffffffffffffffffff",
+        ),
+    ] {
+        assert_eq!(keccak256(expected), hash, "{expected}");
+    }
+}
+
+#[test]
+fn signed_integers() {
+    for (hash, expected) in [
+        (EncodePacked::new().push_int::<8>(-128).finalize(), "80"),
+        (EncodePacked::new().push_int::<8>(127).finalize(), "7f"),
+        (EncodePacked::new().push_int::<24>(-2).finalize(), "fffffe"),
+        (
+            EncodePacked::new().push_int::<64>(5).finalize(),
+            "0000000000000005",
+        ),
+        (
+            EncodePacked::new().push_int::<128>(i128::MIN).finalize(),
+            "80000000000000000000000000000000",
+        ),
+    ] {
+        assert_eq!(keccak256(expected), hash, "{expected}");
+    }
+}
+
+#[test]
+fn bools_and_bytes() {
+    for (hash, expected) in [
+        (EncodePacked::new().push_bool(true).finalize(), "01"),
+        (EncodePacked::new().push_bool(false).finalize(), "00"),
+        (
+            EncodePacked::new()
+                .push_bytes_n(b"\x12\x34\x56\x78")
+                .finalize(),
+            "12345678",
+        ),
+        (EncodePacked::new().push_bytes(&[]).finalize(), ""),
+        (EncodePacked::new().push_string("").finalize(), ""),
+        (
+            EncodePacked::new()
+                .push_bytes(&[0xca, 0xfe])
+                .push_string("abc")
+                .push_bool(true)
+                .finalize(),
+            "cafe61626301",
+        ),
+    ] {
+        assert_eq!(keccak256(expected), hash, "{expected}");
+    }
+}
+
+#[test]
+fn arrays() {
+    for (hash, expected) in [
+        (
+            EncodePacked::new()
+                .push_address_array(&[Address([0x11; 20]), Address([0x22; 20])])
+                .finalize(),
+            concat!(
+                "0000000000000000000000001111111111111111111111111111111111111111",
+                "0000000000000000000000002222222222222222222222222222222222222222",
+            ),
+        ),
+        (
+            EncodePacked::new().push_uint_array::<8>(&[1, 2]).finalize(),
+            concat!(
+                "0000000000000000000000000000000000000000000000000000000000000001",
+                "0000000000000000000000000000000000000000000000000000000000000002",
+            ),
+        ),
+        (
+            EncodePacked::new().push_int_array::<16>(&[-1]).finalize(),
+            "ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff",
+        ),
+        (
+            EncodePacked::new()
+                .push_bool_array(&[true, false])
+                .finalize(),
+            concat!(
+                "0000000000000000000000000000000000000000000000000000000000000001",
+                "0000000000000000000000000000000000000000000000000000000000000000",
+            ),
+        ),
+        (
+            EncodePacked::new()
+                .push_bytes_n_array(&[b"\x12\x34", b"\x56\x78"])
+                .finalize(),
+            concat!(
+                "1234000000000000000000000000000000000000000000000000000000000000",
+                "5678000000000000000000000000000000000000000000000000000000000000",
+            ),
+        ),
+        (
+            EncodePacked::new()
+                .push_word_array(&[[0xab; 32]])
+                .finalize(),
+            "abababababababababababababababababababababababababababababababab",
+        ),
+        (
+            EncodePacked::new().push_uint_array::<64>(&[]).finalize(),
+            "",
+        ),
+    ] {
+        assert_eq!(keccak256(expected), hash, "{expected}");
+    }
+}
+
+#[test]
+fn mixed() {
+    let hash = EncodePacked::new()
+        .push_uint::<8>(1)
+        .push_address_array(&[Address([0x33; 20])])
+        .push_int::<32>(-1)
+        .push_bytes_n(&[0xaa; 32])
+        .finalize();
+
+    assert_eq!(
+        keccak256(concat!(
+            "01",
+            "0000000000000000000000003333333333333333333333333333333333333333",
+            "ffffffff",
+            "aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa",
+        )),
+        hash
+    );
+}
+
+#[test]
+fn continue_keccak() {
+    let hash = EncodePacked::from_keccak(Keccak256::new().update(b"\x19"))
+        .push_uint::<8>(0)
+        .into_keccak()
+        .finalize();
+
+    assert_eq!(keccak256("1900"), hash);
+}
+
+#[test]
+#[should_panic(expected = "value out of range for uintN")]
+fn uint_out_of_range() {
+    EncodePacked::new().push_uint::<8>(0x100);
+}
+
+#[test]
+#[should_panic(expected = "value out of range for intN")]
+fn int_out_of_range() {
+    EncodePacked::new().push_int::<8>(-129);
+}
+
+#[test]
+#[should_panic(expected = "integer width must be a multiple of 8 between 8 and 256 bits")]
+fn invalid_width() {
+    EncodePacked::new().push_uint::<12>(0);
+}
+
+#[test]
+#[should_panic(expected = "bytesN must be 1 to 32 bytes long")]
+fn bytes_n_too_long() {
+    EncodePacked::new().push_bytes_n(&[0; 33]);
+}
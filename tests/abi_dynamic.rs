@@ -0,0 +1,512 @@
+#![cfg(feature = "alloc")]
+//! Expected encodings follow the examples of the Solidity ABI specification
+
+mod common;
+
+use keccak_const::abi;
+use keccak_const::abi::DecodeError;
+use keccak_const::abi::Revert;
+use keccak_const::abi::Type;
+use keccak_const::abi::Value;
+use keccak_const::address::Address;
+use keccak_const::sol_type::I256;
+use keccak_const::sol_type::U256;
+use keccak_const::solidity::function_selector;
+
+fn uint(value: u128) -> Value {
+    let mut word = [0; 32];
+    word[16..].copy_from_slice(&value.to_be_bytes());
+    Value::Uint(U256(word))
+}
+
+fn ty(s: &str) -> Type {
+    Type::parse(s).unwrap()
+}
+
+#[test]
+fn parse_types() {
+    for s in [
+        "uint8",
+        "uint256",
+        "int24",
+        "address",
+        "bool",
+        "bytes1",
+        "bytes32",
+        "bytes",
+        "string",
+        "uint256[]",
+        "uint32[2][]",
+        "(address,(bool,bytes)[3])[]",
+    ] {
+        assert_eq!(s, ty(s).to_string());
+    }
+    assert_eq!(
+        Type::Array(Box::new(Type::FixedArray(Box::new(Type::Uint(32)), 2))),
+        ty("uint32[2][]")
+    );
+    for s in [
+        "",
+        "uint",
+        "int",
+        "uint7",
+        "uint264",
+        "bytes0",
+        "bytes33",
+        "byte",
+        "fixed",
+        "uint256[0]",
+        "uint256[01]",
+        "uint256[",
+        "(uint256",
+        "(uint256,)",
+        "(,)",
+        "()",
+        "(uint256,())",
+        "uint256 ",
+        "address[]x",
+    ] {
+        assert_eq!(None, Type::parse(s), "{s}");
+    }
+}
+
+#[test]
+fn specification_example_f() {
+    // f(uint256,uint32[],bytes10,bytes) with (0x123, [0x456, 0x789], "1234567890",
+    // "Hello, world!")
+    let values = [
+        uint(0x123),
+        Value::Array(vec![uint(0x456), uint(0x789)]),
+        Value::FixedBytes(b"1234567890".to_vec()),
+        Value::Bytes(b"Hello, world!".to_vec()),
+    ];
+    let expected = common::hex_bytes(concat!(
+        "8be65246",
+        "0000000000000000000000000000000000000000000000000000000000000123",
+        "0000000000000000000000000000000000000000000000000000000000000080",
+        "3132333435363738393000000000000000000000000000000000000000000000",
+        "00000000000000000000000000000000000000000000000000000000000000e0",
+        "0000000000000000000000000000000000000000000000000000000000000002",
+        "0000000000000000000000000000000000000000000000000000000000000456",
+        "0000000000000000000000000000000000000000000000000000000000000789",
+        "000000000000000000000000000000000000000000000000000000000000000d",
+        "48656c6c6f2c20776f726c642100000000000000000000000000000000000000",
+    ));
+
+    let calldata = abi::encode_with_selector(
+        function_selector("f(uint256,uint32[],bytes10,bytes)"),
+        &values,
+    );
+    assert_eq!(expected, calldata);
+    assert_eq!(
+        Ok(values.to_vec()),
+        abi::decode(
+            &[ty("uint256"), ty("uint32[]"), ty("bytes10"), ty("bytes")],
+            &calldata[4..]
+        )
+    );
+}
+
+#[test]
+fn specification_example_g() {
+    // g(uint256[][],string[]) with ([[1, 2], [3]], ["one", "two", "three"])
+    let values = [
+        Value::Array(vec![
+            Value::Array(vec![uint(1), uint(2)]),
+            Value::Array(vec![uint(3)]),
+        ]),
+        Value::Array(vec![
+            Value::String("one".into()),
+            Value::String("two".into()),
+            Value::String("three".into()),
+        ]),
+    ];
+    let expected = common::hex_bytes(concat!(
+        "0000000000000000000000000000000000000000000000000000000000000040",
+        "0000000000000000000000000000000000000000000000000000000000000140",
+        "0000000000000000000000000000000000000000000000000000000000000002",
+        "0000000000000000000000000000000000000000000000000000000000000040",
+        "00000000000000000000000000000000000000000000000000000000000000a0",
+        "0000000000000000000000000000000000000000000000000000000000000002",
+        "0000000000000000000000000000000000000000000000000000000000000001",
+        "0000000000000000000000000000000000000000000000000000000000000002",
+        "0000000000000000000000000000000000000000000000000000000000000001",
+        "0000000000000000000000000000000000000000000000000000000000000003",
+        "0000000000000000000000000000000000000000000000000000000000000003",
+        "0000000000000000000000000000000000000000000000000000000000000060",
+        "00000000000000000000000000000000000000000000000000000000000000a0",
+        "00000000000000000000000000000000000000000000000000000000000000e0",
+        "0000000000000000000000000000000000000000000000000000000000000003",
+        "6f6e650000000000000000000000000000000000000000000000000000000000",
+        "0000000000000000000000000000000000000000000000000000000000000003",
+        "74776f0000000000000000000000000000000000000000000000000000000000",
+        "0000000000000000000000000000000000000000000000000000000000000005",
+        "7468726565000000000000000000000000000000000000000000000000000000",
+    ));
+
+    assert_eq!(expected, abi::encode(&values));
+    assert_eq!(
+        Ok(values.to_vec()),
+        abi::decode(&[ty("uint256[][]"), ty("string[]")], &expected)
+    );
+}
+
+#[test]
+fn dynamic_tuples() {
+    // ((uint8,string),bool[2],(bytes,address)[2])
+    let values = [
+        Value::Tuple(vec![uint(7), Value::String("abc".into())]),
+        Value::FixedArray(vec![Value::Bool(true), Value::Bool(false)]),
+        Value::FixedArray(vec![
+            Value::Tuple(vec![
+                Value::Bytes(vec![]),
+                Value::Address(Address([0x11; 20])),
+            ]),
+            Value::Tuple(vec![
+                Value::Bytes(vec![0xff; 33]),
+                Value::Address(Address([0x22; 20])),
+            ]),
+        ]),
+    ];
+    let types = [
+        ty("(uint8,string)"),
+        ty("bool[2]"),
+        ty("(bytes,address)[2]"),
+    ];
+    let encoded = abi::encode(&values);
+
+    assert_eq!(
+        common::hex_bytes(concat!(
+            "0000000000000000000000000000000000000000000000000000000000000080",
+            "0000000000000000000000000000000000000000000000000000000000000001",
+            "0000000000000000000000000000000000000000000000000000000000000000",
+            "0000000000000000000000000000000000000000000000000000000000000100",
+            "0000000000000000000000000000000000000000000000000000000000000007",
+            "0000000000000000000000000000000000000000000000000000000000000040",
+            "0000000000000000000000000000000000000000000000000000000000000003",
+            "6162630000000000000000000000000000000000000000000000000000000000",
+            "0000000000000000000000000000000000000000000000000000000000000040",
+            "00000000000000000000000000000000000000000000000000000000000000a0",
+            "0000000000000000000000000000000000000000000000000000000000000040",
+            "0000000000000000000000001111111111111111111111111111111111111111",
+            "0000000000000000000000000000000000000000000000000000000000000000",
+            "0000000000000000000000000000000000000000000000000000000000000040",
+            "0000000000000000000000002222222222222222222222222222222222222222",
+            "0000000000000000000000000000000000000000000000000000000000000021",
+            "ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff",
+            "ff00000000000000000000000000000000000000000000000000000000000000",
+        )),
+        encoded
+    );
+    assert_eq!(Ok(values.to_vec()), abi::decode(&types, &encoded));
+}
+
+#[test]
+fn strict_validation() {
+    let word = |last: u8| {
+        let mut word = [0; 32];
+        word[31] = last;
+        word
+    };
+    let mut negative = [0xff; 32];
+    negative[31] = 0x80;
+
+    assert_eq!(
+        Ok(vec![Value::Int(I256(negative))]),
+        abi::decode(&[ty("int8")], &negative)
+    );
+    negative[30] = 0x7f;
+    assert_eq!(
+        Err(DecodeError::InvalidPadding),
+        abi::decode(&[ty("int8")], &negative)
+    );
+    assert_eq!(
+        Err(DecodeError::InvalidPadding),
+        abi::decode(&[ty("int8")], &word(0x80).map(|b| b ^ 0xff))
+    );
+    assert_eq!(
+        Err(DecodeError::InvalidPadding),
+        abi::decode(&[ty("bool")], &word(2))
+    );
+    assert_eq!(
+        Err(DecodeError::InvalidPadding),
+        abi::decode(&[ty("uint8")], &{
+            let mut word = word(1);
+            word[30] = 1;
+            word
+        })
+    );
+    assert_eq!(
+        Err(DecodeError::InvalidPadding),
+        abi::decode(&[ty("address")], &[0xff; 32])
+    );
+    assert_eq!(
+        Err(DecodeError::InvalidPadding),
+        abi::decode(&[ty("bytes31")], &[0xff; 32])
+    );
+    assert_eq!(
+        Err(DecodeError::UnexpectedEnd),
+        abi::decode(&[ty("uint256")], &[0; 31])
+    );
+
+    let mut encoded = abi::encode(&[Value::Bytes(vec![0xaa; 3])]);
+    assert_eq!(
+        Ok(vec![Value::Bytes(vec![0xaa; 3])]),
+        abi::decode(&[Type::Bytes], &encoded)
+    );
+    encoded[95] = 1;
+    assert_eq!(
+        Err(DecodeError::InvalidPadding),
+        abi::decode(&[Type::Bytes], &encoded)
+    );
+    encoded[63] = 0x21;
+    assert_eq!(
+        Err(DecodeError::OutOfBounds),
+        abi::decode(&[Type::Bytes], &encoded)
+    );
+    encoded[31] = 0x60;
+    assert_eq!(
+        Err(DecodeError::UnexpectedEnd),
+        abi::decode(&[Type::Bytes], &encoded)
+    );
+    encoded[31] = 0x80;
+    assert_eq!(
+        Err(DecodeError::OutOfBounds),
+        abi::decode(&[Type::Bytes], &encoded)
+    );
+    encoded[0] = 1;
+    assert_eq!(
+        Err(DecodeError::OutOfBounds),
+        abi::decode(&[Type::Bytes], &encoded)
+    );
+
+    let invalid_utf8 = abi::encode(&[Value::Bytes(vec![0xff])]);
+    assert_eq!(
+        Err(DecodeError::InvalidUtf8),
+        abi::decode(&[Type::String], &invalid_utf8)
+    );
+
+    // an array claiming more elements than the data could hold
+    let mut huge = abi::encode(&[Value::Array(vec![uint(1)])]);
+    huge[32..64].copy_from_slice(&[0xff; 32]);
+    huge[32..56].fill(0);
+    assert_eq!(
+        Err(DecodeError::OutOfBounds),
+        abi::decode(&[ty("uint256[]")], &huge)
+    );
+}
+
+#[test]
+fn overlapping_tails() {
+    // each array has 64 elements whose offsets all point to the same array of
+    // the next level, so the data holds 5 * 65 words but the decoded value
+    // would hold 64^5
+    let word = |value: usize| {
+        let mut word = [0; 32];
+        word[24..].copy_from_slice(&(value as u64).to_be_bytes());
+        word
+    };
+    let mut data = word(32).to_vec();
+    for level in 0..5 {
+        data.extend_from_slice(&word(64));
+        for _ in 0..64 {
+            data.extend_from_slice(&word(if level < 4 { 64 * 32 } else { 1 }));
+        }
+    }
+    assert_eq!(
+        Err(DecodeError::Overlapping),
+        abi::decode(&[ty("uint256[][][][][]")], &data)
+    );
+
+    // two strings sharing a tail
+    let mut shared = abi::encode(&[Value::String("shared".into()), uint(0)]);
+    shared[63] = 0x40;
+    assert_eq!(
+        Err(DecodeError::Overlapping),
+        abi::decode(&[Type::String, Type::String], &shared)
+    );
+}
+
+#[test]
+fn revert_data() {
+    const ERRORS: [&str; 2] = [
+        "InsufficientBalance(address,uint256,uint256)",
+        "Unauthorized()",
+    ];
+
+    let error = common::hex_bytes(concat!(
+        "08c379a0",
+        "0000000000000000000000000000000000000000000000000000000000000020",
+        "000000000000000000000000000000000000000000000000000000000000001a",
+        "4e6f7420656e6f7567682045746865722070726f76696465642e000000000000",
+    ));
+    assert_eq!(
+        Ok(Revert::Error("Not enough Ether provided.".into())),
+        abi::decode_revert(&error, &ERRORS)
+    );
+
+    let panic = common::hex_bytes(concat!(
+        "4e487b71",
+        "0000000000000000000000000000000000000000000000000000000000000011",
+    ));
+    let mut code = [0; 32];
+    code[31] = 0x11;
+    assert_eq!(
+        Ok(Revert::Panic(U256(code))),
+        abi::decode_revert(&panic, &ERRORS)
+    );
+
+    let values = vec![Value::Address(Address([0x33; 20])), uint(100), uint(200)];
+    let custom = abi::encode_with_selector([0xdb, 0x42, 0x14, 0x4d], &values);
+    assert_eq!(
+        Ok(Revert::Custom(0, values)),
+        abi::decode_revert(&custom, &ERRORS)
+    );
+    assert_eq!(
+        Ok(Revert::Custom(1, vec![])),
+        abi::decode_revert(&[0x82, 0xb4, 0x29, 0x00], &ERRORS)
+    );
+
+    assert_eq!(
+        Ok(Revert::Unknown(vec![])),
+        abi::decode_revert(&[], &ERRORS)
+    );
+    assert_eq!(
+        Ok(Revert::Unknown(vec![0x12, 0x34, 0x56, 0x78, 0x9a])),
+        abi::decode_revert(&[0x12, 0x34, 0x56, 0x78, 0x9a], &ERRORS)
+    );
+    assert_eq!(
+        Err(DecodeError::UnexpectedEnd),
+        abi::decode_revert(&error[..error.len() - 1], &ERRORS)
+    );
+
+    let selector = function_selector("Unsupported(function)");
+    assert_eq!(
+        Err(DecodeError::InvalidSignature),
+        abi::decode_revert(&selector, &["Unsupported(function)"])
+    );
+    for signature in [
+        "Foo(uint)",
+        "Foo(address x)",
+        "Foo (uint256)",
+        "1Foo()",
+        "Foo",
+    ] {
+        assert_eq!(
+            Err(DecodeError::InvalidSignature),
+            abi::decode_revert(&selector, &[signature]),
+            "{signature}"
+        );
+    }
+}
+
+/// A xorshift generator, so failures are reproducible
+struct Rng(u64);
+
+impl Rng {
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    fn below(&mut self, n: usize) -> usize {
+        (self.next() % n as u64) as usize
+    }
+
+    fn bytes(&mut self, len: usize) -> Vec<u8> {
+        (0..len).map(|_| self.next() as u8).collect()
+    }
+}
+
+fn random_type(rng: &mut Rng, depth: usize) -> Type {
+    match rng.below(if depth == 0 { 7 } else { 10 }) {
+        0 => Type::Uint(8 * (1 + rng.below(32))),
+        1 => Type::Int(8 * (1 + rng.below(32))),
+        2 => Type::Address,
+        3 => Type::Bool,
+        4 => Type::FixedBytes(1 + rng.below(32)),
+        5 => Type::Bytes,
+        6 => Type::String,
+        7 => Type::Array(Box::new(random_type(rng, depth - 1))),
+        8 => Type::FixedArray(Box::new(random_type(rng, depth - 1)), 1 + rng.below(3)),
+        // Solidity has no empty tuples, and arrays of them cannot be decoded
+        _ => Type::Tuple(
+            (0..1 + rng.below(3))
+                .map(|_| random_type(rng, depth - 1))
+                .collect(),
+        ),
+    }
+}
+
+fn random_value(rng: &mut Rng, ty: &Type) -> Value {
+    match ty {
+        Type::Uint(bits) => {
+            let mut word = [0; 32];
+            word[32 - bits / 8..].copy_from_slice(&rng.bytes(bits / 8));
+            Value::Uint(U256(word))
+        }
+        Type::Int(bits) => {
+            let bytes = rng.bytes(bits / 8);
+            let mut word = if bytes[0] & 0x80 == 0 {
+                [0; 32]
+            } else {
+                [0xff; 32]
+            };
+            word[32 - bits / 8..].copy_from_slice(&bytes);
+            Value::Int(I256(word))
+        }
+        Type::Address => Value::Address(Address(rng.bytes(20).try_into().unwrap())),
+        Type::Bool => Value::Bool(rng.below(2) == 1),
+        Type::FixedBytes(len) => Value::FixedBytes(rng.bytes(*len)),
+        Type::Bytes => {
+            let len = rng.below(70);
+            Value::Bytes(rng.bytes(len))
+        }
+        Type::String => Value::String(
+            (0..rng.below(40))
+                .map(|_| ['a', 'ß', '€', '🦀'][rng.below(4)])
+                .collect(),
+        ),
+        Type::Array(ty) => Value::Array((0..rng.below(4)).map(|_| random_value(rng, ty)).collect()),
+        Type::FixedArray(ty, len) => {
+            Value::FixedArray((0..*len).map(|_| random_value(rng, ty)).collect())
+        }
+        Type::Tuple(types) => Value::Tuple(types.iter().map(|ty| random_value(rng, ty)).collect()),
+    }
+}
+
+#[test]
+fn round_trips() {
+    let mut rng = Rng(0x853c49e6748fea9b);
+    for _ in 0..500 {
+        let types: Vec<Type> = (0..rng.below(5))
+            .map(|_| random_type(&mut rng, 3))
+            .collect();
+        let values: Vec<Value> = types.iter().map(|ty| random_value(&mut rng, ty)).collect();
+        let encoded = abi::encode(&values);
+
+        assert_eq!(0, encoded.len() % 32);
+        assert_eq!(
+            Ok(values.clone()),
+            abi::decode(&types, &encoded),
+            "{types:?}"
+        );
+        let tuple = Type::Tuple(types.clone());
+        let tuple_encoded = abi::encode(&[Value::Tuple(values.clone())]);
+        assert_eq!(
+            Ok(vec![Value::Tuple(values)]),
+            abi::decode(&[tuple], &tuple_encoded),
+        );
+
+        // corrupted data must be rejected or decode to something, never panic
+        if !encoded.is_empty() {
+            let mut corrupted = encoded.clone();
+            let i = rng.below(corrupted.len());
+            corrupted[i] ^= 1 << rng.below(8);
+            let _ = abi::decode(&types, &corrupted);
+            let _ = abi::decode(&types, &encoded[..rng.below(encoded.len())]);
+        }
+    }
+}
@@ -0,0 +1,364 @@
+//! Expected values come from the specifications and from mainnet:
+//!
+//! - the deterministic deployment proxy and the ERC-1820 registry, deployed
+//!   with `CREATE`, and the Uniswap V2 USDC/WETH pair, deployed with `CREATE2`
+//! - the `CREATE2` examples of EIP-1014
+//! - the hash of the CREATE3 proxy init code, `_PROXY_INITCODE_HASH` in
+//!   Solady's `CREATE3`
+//! - the ERC-1167 init code
+//! - the checksum test vectors of EIP-55 and EIP-1191
+//! - the addresses of the private keys 1 and 2 and of the first Hardhat
+//!   account, and the node ID of the example record of EIP-778
+//!
+//! The addresses of the `create` and `create3` tests were computed with a
+//! separate Python implementation of RLP and Keccak-256.
+
+mod common;
+
+use keccak_const::address;
+use keccak_const::address::Address;
+use keccak_const::Keccak256;
+
+fn keccak256(data: &[u8]) -> [u8; 32] {
+    Keccak256::new().update(data).finalize()
+}
+
+#[test]
+fn create() {
+    let deployer = Address::from_hex("0x6ac7ea33f8831ea9dcc53393aaa88b25a785dbf0");
+
+    for (nonce, expected) in [
+        (0, "0xcd234a471b72ba2f1ccf0a70fcaba648a5eecd8d"),
+        (1, "0x343c43a37d37dff08ae8c4a11544c718abb4fcf8"),
+        (2, "0xf778b86fa74e846c4f0a1fbd1335fe81c00a0c91"),
+        (3, "0xfffd933a0bc612844eaf0c6fe3e5b8e9b6c1d19c"),
+        (0x7f, "0x06d9a77f5e4b311bae8d559db9cdb4df94104aa0"),
+        (0x80, "0x08e190dcb7b73f5fcdabb43e102215c83659a76d"),
+        (0xff, "0x3ef7c1a519e4b4431e317d7839340e3139b03c65"),
+        (0x100, "0x3837c1ae70354f670550c746580199ac6a73cb0a"),
+        (0xffffff, "0x0bdba1d0a9e4d61885e0688be1daed6ad1fceb0c"),
+        (1 << 32, "0xf4bf328880432064068338f915c49f817dc4ce18"),
+        (u64::MAX, "0x9bc924993b60399df164c3763a964301d3db95ca"),
+    ] {
+        assert_eq!(
+            Address::from_hex(expected),
+            address::create_address(&deployer, nonce),
+            "nonce {nonce}"
+        );
+    }
+}
+
+#[test]
+fn create_mainnet() {
+    // Deterministic deployment proxy
+    const PROXY: Address = address::create_address(
+        &Address([
+            0x3f, 0xab, 0x18, 0x46, 0x22, 0xdc, 0x19, 0xb6, 0x10, 0x93, 0x49, 0xb9, 0x48, 0x11,
+            0x49, 0x3b, 0xf2, 0xa4, 0x53, 0x62,
+        ]),
+        0,
+    );
+
+    assert_eq!(
+        Address::from_hex("0x4e59b44847b379578588920ca78fbf26c0b4956c"),
+        PROXY
+    );
+    // ERC-1820 registry
+    assert_eq!(
+        Address::from_hex("0x1820a4b7618bde71dce8cdc73aab6c95905fad24"),
+        address::create_address(
+            &Address::from_hex("0xa990077c3205cbdf861e17fa532eeb069ce9ff96"),
+            0
+        )
+    );
+}
+
+#[test]
+fn create2_eip1014() {
+    // the examples of EIP-1014
+    let zero = Address::from_hex("0x0000000000000000000000000000000000000000");
+    let deadbeef = Address::from_hex("0xdeadbeef00000000000000000000000000000000");
+    let low_deadbeef = Address::from_hex("0x00000000000000000000000000000000deadbeef");
+    let mut feed = [0; 32];
+    feed[12..14].copy_from_slice(&[0xfe, 0xed]);
+    let mut cafebabe = [0; 32];
+    cafebabe[28..].copy_from_slice(&[0xca, 0xfe, 0xba, 0xbe]);
+
+    for (deployer, salt, init_code, expected) in [
+        (
+            zero,
+            [0; 32],
+            &[0x00][..],
+            "0x4d1a2e2bb4f88f0250f26ffff098b0b30b26bf38",
+        ),
+        (
+            deadbeef,
+            [0; 32],
+            &[0x00],
+            "0xb928f69bb1d91cd65274e3c79d8986362984fda3",
+        ),
+        (
+            deadbeef,
+            feed,
+            &[0x00],
+            "0xd04116cdd17bebe565eb2422f2497e06cc1c9833",
+        ),
+        (
+            zero,
+            [0; 32],
+            &[0xde, 0xad, 0xbe, 0xef],
+            "0x70f2b2914a2a4b783faefb75f459a580616fcb5e",
+        ),
+        (
+            low_deadbeef,
+            cafebabe,
+            &[0xde, 0xad, 0xbe, 0xef],
+            "0x60f3f640a8508fc6a86d45df051962668e1e8ac7",
+        ),
+        (
+            low_deadbeef,
+            cafebabe,
+            &[0xde, 0xad, 0xbe, 0xef].repeat(11),
+            "0x1d8bfdc5d46dc4f61d6b6115972536ebe6a8854c",
+        ),
+        (
+            zero,
+            [0; 32],
+            &[],
+            "0xe33c0c7f7df4809055c3eba6c09cfe4baf1bd9e0",
+        ),
+    ] {
+        assert_eq!(
+            Address::from_hex(expected),
+            address::create2_address(&deployer, &salt, &keccak256(init_code))
+        );
+    }
+}
+
+#[test]
+fn create2_uniswap_v2_pair() {
+    // USDC/WETH
+    let factory = Address::from_hex("0x5c69bee701ef814a2b6a3edd4b1652cb9cc5aa6f");
+    let salt = Keccak256::new()
+        .update(&Address::from_hex("0xa0b86991c6218b36c1d19d4a2e9eb0ce3606eb48").0)
+        .update(&Address::from_hex("0xc02aaa39b223fe8d0a0e5c4f27ead9083c756cc2").0)
+        .finalize();
+    let init_code_hash = [
+        0x96, 0xe8, 0xac, 0x42, 0x77, 0x19, 0x8f, 0xf8, 0xb6, 0xf7, 0x85, 0x47, 0x8a, 0xa9, 0xa3,
+        0x9f, 0x40, 0x3c, 0xb7, 0x68, 0xdd, 0x02, 0xcb, 0xee, 0x32, 0x6c, 0x3e, 0x7d, 0xa3, 0x48,
+        0x84, 0x5f,
+    ];
+
+    assert_eq!(
+        Address::from_hex("0xb4e16d0168e52d35cacd2c6185b44281ec28c9dc"),
+        address::create2_address(&factory, &salt, &init_code_hash)
+    );
+}
+
+#[test]
+fn create3() {
+    let deployer = Address::from_hex("0x9fbb3df7c40da2e5a0de984ffe2ccb7c47cd0abf");
+    let salt = [0x5a; 32];
+    let proxy = address::create2_address(
+        &deployer,
+        &salt,
+        &keccak256(&address::CREATE3_PROXY_INIT_CODE),
+    );
+
+    // `_PROXY_INITCODE_HASH` of Solady's `CREATE3`
+    assert_eq!(
+        [
+            0x21, 0xc3, 0x5d, 0xbe, 0x1b, 0x34, 0x4a, 0x24, 0x88, 0xcf, 0x33, 0x21, 0xd6, 0xce,
+            0x54, 0x2f, 0x8e, 0x9f, 0x30, 0x55, 0x44, 0xff, 0x09, 0xe4, 0x99, 0x3a, 0x62, 0x31,
+            0x9a, 0x49, 0x7c, 0x1f,
+        ],
+        keccak256(&address::CREATE3_PROXY_INIT_CODE)
+    );
+    assert_eq!(
+        address::create_address(&proxy, 1),
+        address::create3_address(&deployer, &salt)
+    );
+    assert_eq!(
+        Address::from_hex("0x1d2b77af05625258ec8f4e95edfbeb9dcb5b1676"),
+        address::create3_address(&deployer, &salt)
+    );
+}
+
+#[test]
+fn minimal_proxy() {
+    const INIT_CODE: [u8; 55] = address::minimal_proxy_init_code(&Address([0xbe; 20]));
+
+    // the init code of ERC-1167 with the implementation address filled in
+
+    assert_eq!(
+        [
+            0x3d, 0x60, 0x2d, 0x80, 0x60, 0x0a, 0x3d, 0x39, 0x81, 0xf3, 0x36, 0x3d, 0x3d, 0x37,
+            0x3d, 0x3d, 0x3d, 0x36, 0x3d, 0x73, 0xbe, 0xbe, 0xbe, 0xbe, 0xbe, 0xbe, 0xbe, 0xbe,
+            0xbe, 0xbe, 0xbe, 0xbe, 0xbe, 0xbe, 0xbe, 0xbe, 0xbe, 0xbe, 0xbe, 0xbe, 0x5a, 0xf4,
+            0x3d, 0x82, 0x80, 0x3e, 0x90, 0x3d, 0x91, 0x60, 0x2b, 0x57, 0xfd, 0x5b, 0xf3,
+        ],
+        INIT_CODE
+    );
+}
+
+/// The test vectors of EIP-55
+const EIP55: [&str; 8] = [
+    "0x52908400098527886E0F7030069857D2E4169EE7",
+    "0x8617E340B3D01FA5F11F306F4090FD50E238070D",
+    "0xde709f2102306220921060314715629080e2fb77",
+    "0x27b1fdb04752bbc536007a920d24acb045561c26",
+    "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed",
+    "0xfB6916095ca1df60bB79Ce92cE3Ea74c37c5d359",
+    "0xdbF03B407c01E7cD3CBea99509d93f8DDDC8C6FB",
+    "0xD1220A0cf47c7B9Be7A2E6BA89F429762e7b9aDb",
+];
+
+#[test]
+fn eip55() {
+    const WETH: Address = Address::from_hex("0xC02aaA39b223FE8D0A0e5C4F27eAD9083C756Cc2");
+
+    assert_eq!(
+        Address::from_hex("0xc02aaa39b223fe8d0a0e5c4f27ead9083c756cc2"),
+        WETH
+    );
+    for hex in EIP55 {
+        let address = Address::from_hex(hex);
+
+        assert_eq!(hex.as_bytes(), address.to_checksum_hex());
+        assert_eq!(hex, address.to_string());
+        assert_eq!(address, Address::from_hex(&hex.to_lowercase()));
+        assert_eq!(
+            address,
+            Address::from_hex(&hex.to_uppercase().replace("0X", "0x"))
+        );
+    }
+}
+
+#[test]
+fn eip1191() {
+    // the test vectors of EIP-1191 for RSK mainnet and testnet
+    const RSK_MAINNET: [&str; 5] = [
+        "0x5aaEB6053f3e94c9b9a09f33669435E7ef1bEAeD",
+        "0xFb6916095cA1Df60bb79ce92cE3EA74c37c5d359",
+        "0xDBF03B407c01E7CD3cBea99509D93F8Dddc8C6FB",
+        "0xD1220A0Cf47c7B9BE7a2e6ba89F429762E7B9adB",
+        "0x52908400098527886E0F7030069857D2E4169ee7",
+    ];
+    const RSK_TESTNET: [&str; 5] = [
+        "0x5aAeb6053F3e94c9b9A09F33669435E7EF1BEaEd",
+        "0xFb6916095CA1dF60bb79CE92ce3Ea74C37c5D359",
+        "0xdbF03B407C01E7cd3cbEa99509D93f8dDDc8C6fB",
+        "0xd1220a0CF47c7B9Be7A2E6Ba89f429762E7b9adB",
+        "0x52908400098527886E0F7030069857D2e4169EE7",
+    ];
+
+    for (i, hex) in EIP55[4..].iter().chain(&EIP55[..1]).enumerate() {
+        let address = Address::from_hex(hex);
+
+        assert_eq!(
+            RSK_MAINNET[i].as_bytes(),
+            address.to_checksum_hex_with_chain_id(30)
+        );
+        assert_eq!(
+            RSK_TESTNET[i].as_bytes(),
+            address.to_checksum_hex_with_chain_id(31)
+        );
+        assert_eq!(address, Address::from_hex_with_chain_id(RSK_MAINNET[i], 30));
+        assert_eq!(address, Address::from_hex_with_chain_id(RSK_TESTNET[i], 31));
+    }
+}
+
+#[test]
+#[should_panic = "invalid address checksum"]
+fn wrong_checksum() {
+    Address::from_hex("0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAeD");
+}
+
+#[test]
+#[should_panic = "invalid address checksum"]
+fn wrong_chain_checksum() {
+    Address::from_hex_with_chain_id("0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed", 30);
+}
+
+#[test]
+#[should_panic = "address must be 0x followed by 40 hex digits"]
+fn missing_prefix() {
+    Address::from_hex("5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed");
+}
+
+#[test]
+#[should_panic = "address must be 0x followed by 40 hex digits"]
+fn short_address() {
+    Address::from_hex("0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeA");
+}
+
+#[test]
+#[should_panic = "address must be 0x followed by 40 hex digits"]
+fn invalid_digit() {
+    Address::from_hex("0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAeg");
+}
+
+#[test]
+fn pubkeys() {
+    for (pubkey_hex, expected) in [
+        // private key 1, the generator
+        (
+            "79be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798\
+             483ada7726a3c4655da4fbfc0e1108a8fd17b448a68554199c47d08ffb10d4b8",
+            "0x7E5F4552091A69125d5DfCb7b8C2659029395Bdf",
+        ),
+        // private key 2
+        (
+            "c6047f9441ed7d6d3045406e95c07cd85c778e4b8cef3ca7abac09b95c709ee5\
+             1ae168fea63dc339a3c58419466ceaeef7f632653266d0e1236431a950cfe52a",
+            "0x2B5AD5c4795c026514f8317c7a215E218DcCD6cF",
+        ),
+        // the first Hardhat and Anvil development account
+        (
+            "8318535b54105d4a7aae60c08fc45f9687181b4fdfc625bd1a753fa7397fed75\
+             3547f11ca8696646f2f3acb08e31016afac23e630c5d11f59f61fef57b0d2aa5",
+            "0xf39Fd6e51aad88F6F4ce6aB8827279cffFb92266",
+        ),
+    ] {
+        let pubkey: [u8; 64] = common::hex(pubkey_hex);
+        let mut uncompressed = [0x04; 65];
+        uncompressed[1..].copy_from_slice(&pubkey);
+        let expected = Address::from_hex(expected);
+
+        assert_eq!(expected, address::address_from_pubkey(&pubkey));
+        assert_eq!(
+            expected,
+            address::address_from_uncompressed_pubkey(&uncompressed)
+        );
+        assert!(address::pubkey_matches(&expected, &pubkey));
+        assert!(!address::pubkey_matches(&Address([0; 20]), &pubkey));
+    }
+}
+
+#[test]
+#[should_panic = "uncompressed public key must start with 0x04"]
+fn compressed_pubkey_prefix() {
+    address::address_from_uncompressed_pubkey(&[0x02; 65]);
+}
+
+#[test]
+fn node_id() {
+    // the example record of EIP-778
+    let pubkey: [u8; 64] = common::hex(
+        "ca634cae0d49acb401d8a4c6b6fe8c55b70d115bf400769cc1400f3258cd3138\
+         7574077f301b421bc84df7266c44e9e6d569fc56be00812904767bf5ccd1fc7f",
+    );
+
+    assert_eq!(
+        [
+            0xa4, 0x48, 0xf2, 0x4c, 0x6d, 0x18, 0xe5, 0x75, 0x45, 0x3d, 0xb1, 0x31, 0x71, 0x56,
+            0x2b, 0x71, 0x99, 0x98, 0x73, 0xdb, 0x5b, 0x28, 0x6d, 0xf9, 0x57, 0xaf, 0x19, 0x9e,
+            0xc9, 0x46, 0x17, 0xf7,
+        ],
+        address::node_id(&pubkey)
+    );
+    assert_eq!(
+        address::node_id(&pubkey)[12..],
+        address::address_from_pubkey(&pubkey).0
+    );
+}
@@ -0,0 +1,132 @@
+#![cfg(feature = "std")]
+
+use keccak_const::address;
+use keccak_const::address::Address;
+use keccak_const::address::Pattern;
+use keccak_const::address::Progress;
+use keccak_const::address::SaltMiner;
+use std::thread;
+
+const DEPLOYER: Address = Address([0x4e; 20]);
+
+const INIT_CODE_HASH: [u8; 32] = [0x21; 32];
+
+fn salt(prefix: &[u8; 24], counter: u64) -> [u8; 32] {
+    let mut salt = [0; 32];
+    salt[..24].copy_from_slice(prefix);
+    salt[24..].copy_from_slice(&counter.to_be_bytes());
+    salt
+}
+
+#[test]
+fn patterns() {
+    let address = Address([
+        0x00, 0x00, 0xab, 0xcd, 0x11, 0x11, 0x11, 0x11, 0x11, 0x11, 0x11, 0x11, 0x11, 0x11, 0x11,
+        0x11, 0x11, 0x11, 0xbe, 0xef,
+    ]);
+
+    assert!(Pattern::leading_zeros(0).matches(&Address([0xff; 20])));
+    assert!(Pattern::leading_zeros(2).matches(&address));
+    assert!(!Pattern::leading_zeros(3).matches(&address));
+    assert!(Pattern::prefix(&[0x00, 0x00, 0xab]).matches(&address));
+    assert!(!Pattern::prefix(&[0xab]).matches(&address));
+    assert!(Pattern::suffix(&[0xbe, 0xef]).matches(&address));
+    assert!(!Pattern::suffix(&[0xbe, 0xee]).matches(&address));
+    assert!(Pattern::leading_zeros(2)
+        .and(&Pattern::suffix(&[0xbe, 0xef]))
+        .matches(&address));
+    assert!(Pattern::masked(&[0xf0; 20], &[0x0f; 20]).matches(&Address([0x0f; 20])));
+    assert!(!Pattern::masked(&[0xf0; 20], &[0x1f; 20]).matches(&Address([0x0f; 20])));
+}
+
+#[test]
+#[should_panic = "patterns match no address"]
+fn disjoint_patterns() {
+    Pattern::prefix(&[0x01]).and(&Pattern::prefix(&[0x02]));
+}
+
+#[test]
+#[should_panic = "pattern is longer than an address"]
+fn too_many_leading_zeros() {
+    Pattern::leading_zeros(21);
+}
+
+#[test]
+fn single_thread_finds_lowest_counter() {
+    let pattern = Pattern::leading_zeros(1).and(&Pattern::suffix(&[0x00]));
+    let prefix = [0x77; 24];
+    let progress = Progress::new();
+
+    let (found, address) = SaltMiner::new(&DEPLOYER, &INIT_CODE_HASH, pattern)
+        .salt_prefix(&prefix)
+        .threads(1)
+        .mine(&progress)
+        .unwrap();
+
+    let counter = (0..)
+        .find(|&counter| {
+            pattern.matches(&address::create2_address(
+                &DEPLOYER,
+                &salt(&prefix, counter),
+                &INIT_CODE_HASH,
+            ))
+        })
+        .unwrap();
+    assert_eq!(salt(&prefix, counter), found);
+    assert_eq!(
+        address::create2_address(&DEPLOYER, &found, &INIT_CODE_HASH),
+        address
+    );
+    assert!(progress.attempts() <= counter + 1);
+    assert!(progress.attempts() + 1024 > counter);
+}
+
+#[test]
+fn multiple_threads() {
+    let pattern = Pattern::prefix(&[0xca]).and(&Pattern::suffix(&[0xfe]));
+
+    let (salt, address) = SaltMiner::new(&DEPLOYER, &INIT_CODE_HASH, pattern)
+        .threads(4)
+        .mine(&Progress::new())
+        .unwrap();
+
+    assert_eq!([0; 24], salt[..24]);
+    assert!(pattern.matches(&address));
+    assert_eq!(
+        address::create2_address(&DEPLOYER, &salt, &INIT_CODE_HASH),
+        address
+    );
+}
+
+#[test]
+fn cancellation() {
+    let progress = Progress::new();
+    let miner = SaltMiner::new(&DEPLOYER, &INIT_CODE_HASH, Pattern::leading_zeros(20)).threads(2);
+
+    let found = thread::scope(|scope| {
+        let search = scope.spawn(|| miner.mine(&progress));
+        while progress.attempts() < 4096 {
+            thread::yield_now();
+        }
+        progress.cancel();
+        search.join().unwrap()
+    });
+
+    assert_eq!(None, found);
+    assert!(progress.is_cancelled());
+    assert!(progress.attempts() >= 4096);
+}
+
+#[test]
+fn cancelled_before_start() {
+    let progress = Progress::new();
+    progress.cancel();
+
+    assert_eq!(
+        None,
+        SaltMiner::new(&DEPLOYER, &INIT_CODE_HASH, Pattern::leading_zeros(20))
+            .threads(3)
+            .mine(&progress)
+    );
+    assert!(progress.attempts() <= 3 * 1024);
+}
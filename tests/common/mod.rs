@@ -0,0 +1,22 @@
+//! Helpers shared by the integration tests
+
+// Each test crate compiles its own copy and uses only some of the helpers
+#![allow(dead_code)]
+
+/// Decodes `N` bytes from hex digits without a `0x` prefix
+pub fn hex<const N: usize>(hex: &str) -> [u8; N] {
+    assert_eq!(2 * N, hex.len(), "expected {N} bytes of hex");
+    let mut bytes = [0; N];
+    for (i, byte) in bytes.iter_mut().enumerate() {
+        *byte = u8::from_str_radix(&hex[2 * i..2 + 2 * i], 16).unwrap();
+    }
+    bytes
+}
+
+/// Decodes bytes from hex digits without a `0x` prefix
+pub fn hex_bytes(hex: &str) -> Vec<u8> {
+    (0..hex.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).unwrap())
+        .collect()
+}
@@ -0,0 +1,208 @@
+//! `DigestInfo` outputs are checked with a minimal DER decoder that is
+//! independent of the encodings in the crate.
+
+use keccak_const::Sha3_224;
+use keccak_const::Sha3_256;
+use keccak_const::Sha3_384;
+use keccak_const::Sha3_512;
+use keccak_const::Shake128;
+use keccak_const::Shake256;
+
+const INPUT: &[u8] = b"The quick brown fox jumps over the lazy dog";
+
+/// Splits a DER encoded value into its tag, contents and trailing bytes
+fn parse_tlv(input: &[u8]) -> (u8, &[u8], &[u8]) {
+    let tag = input[0];
+    let (len, header) = match input[1] {
+        len @ 0..=0x7f => (len as usize, 2),
+        0x81 => {
+            assert!(input[2] >= 0x80, "non-minimal length");
+            (input[2] as usize, 3)
+        }
+        _ => panic!("unsupported length"),
+    };
+    let end = header + len;
+    (tag, &input[header..end], &input[end..])
+}
+
+fn decode_oid(contents: &[u8]) -> String {
+    let mut arcs = Vec::new();
+    let mut value = 0u64;
+    for &byte in contents {
+        value = (value << 7) | u64::from(byte & 0x7f);
+        if byte & 0x80 == 0 {
+            if arcs.is_empty() {
+                let first = (value / 40).min(2);
+                arcs.push(first);
+                arcs.push(value - 40 * first);
+            } else {
+                arcs.push(value);
+            }
+            value = 0;
+        }
+    }
+    assert_eq!(0, value, "truncated arc");
+    arcs.iter()
+        .map(u64::to_string)
+        .collect::<Vec<_>>()
+        .join(".")
+}
+
+fn decode_oid_tlv(input: &[u8]) -> String {
+    let (tag, contents, rest) = parse_tlv(input);
+    assert_eq!(0x06, tag);
+    assert!(rest.is_empty());
+    decode_oid(contents)
+}
+
+/// Decodes `DigestInfo ::= SEQUENCE { AlgorithmIdentifier, OCTET STRING }`
+/// returning the algorithm, whether parameters were NULL and the digest
+fn decode_digest_info(input: &[u8]) -> (String, bool, Vec<u8>) {
+    let (tag, digest_info, rest) = parse_tlv(input);
+    assert_eq!(0x30, tag);
+    assert!(rest.is_empty());
+
+    let (tag, algorithm_identifier, rest) = parse_tlv(digest_info);
+    assert_eq!(0x30, tag);
+    let (tag, digest, trailing) = parse_tlv(rest);
+    assert_eq!(0x04, tag);
+    assert!(trailing.is_empty());
+
+    let (tag, oid, parameters) = parse_tlv(algorithm_identifier);
+    assert_eq!(0x06, tag);
+    let null_parameters = match parameters {
+        [] => false,
+        [0x05, 0x00] => true,
+        _ => panic!("unexpected parameters"),
+    };
+
+    (decode_oid(oid), null_parameters, digest.to_vec())
+}
+
+#[test]
+fn sha3_224_digest_info() {
+    const DIGEST_INFO: [u8; Sha3_224::DIGEST_INFO_LEN] = Sha3_224::new()
+        .update(INPUT)
+        .digest_info::<{ Sha3_224::DIGEST_INFO_LEN }>();
+    let (oid, null_parameters, digest) = decode_digest_info(&DIGEST_INFO);
+
+    assert_eq!("2.16.840.1.101.3.4.2.7", oid);
+    assert!(null_parameters);
+    assert_eq!(Sha3_224::new().update(INPUT).finalize().to_vec(), digest);
+    assert_eq!(oid, decode_oid_tlv(Sha3_224::OID));
+    assert_eq!(
+        "2.16.840.1.101.3.4.3.13",
+        decode_oid_tlv(Sha3_224::RSA_SIGNATURE_OID)
+    );
+    assert_eq!(
+        "2.16.840.1.101.3.4.3.9",
+        decode_oid_tlv(Sha3_224::ECDSA_SIGNATURE_OID)
+    );
+}
+
+#[test]
+fn sha3_256_digest_info() {
+    let (oid, null_parameters, digest) =
+        decode_digest_info(&Sha3_256::new().update(INPUT).digest_info::<51>());
+
+    assert_eq!("2.16.840.1.101.3.4.2.8", oid);
+    assert!(null_parameters);
+    assert_eq!(Sha3_256::new().update(INPUT).finalize().to_vec(), digest);
+    assert_eq!(oid, decode_oid_tlv(Sha3_256::OID));
+    assert_eq!(
+        "2.16.840.1.101.3.4.3.14",
+        decode_oid_tlv(Sha3_256::RSA_SIGNATURE_OID)
+    );
+    assert_eq!(
+        "2.16.840.1.101.3.4.3.10",
+        decode_oid_tlv(Sha3_256::ECDSA_SIGNATURE_OID)
+    );
+}
+
+#[test]
+fn sha3_384_digest_info() {
+    let (oid, null_parameters, digest) =
+        decode_digest_info(&Sha3_384::new().update(INPUT).digest_info::<67>());
+
+    assert_eq!("2.16.840.1.101.3.4.2.9", oid);
+    assert!(null_parameters);
+    assert_eq!(Sha3_384::new().update(INPUT).finalize().to_vec(), digest);
+    assert_eq!(oid, decode_oid_tlv(Sha3_384::OID));
+    assert_eq!(
+        "2.16.840.1.101.3.4.3.15",
+        decode_oid_tlv(Sha3_384::RSA_SIGNATURE_OID)
+    );
+    assert_eq!(
+        "2.16.840.1.101.3.4.3.11",
+        decode_oid_tlv(Sha3_384::ECDSA_SIGNATURE_OID)
+    );
+}
+
+#[test]
+fn sha3_512_digest_info() {
+    let (oid, null_parameters, digest) =
+        decode_digest_info(&Sha3_512::new().update(INPUT).digest_info::<83>());
+
+    assert_eq!("2.16.840.1.101.3.4.2.10", oid);
+    assert!(null_parameters);
+    assert_eq!(Sha3_512::new().update(INPUT).finalize().to_vec(), digest);
+    assert_eq!(oid, decode_oid_tlv(Sha3_512::OID));
+    assert_eq!(
+        "2.16.840.1.101.3.4.3.16",
+        decode_oid_tlv(Sha3_512::RSA_SIGNATURE_OID)
+    );
+    assert_eq!(
+        "2.16.840.1.101.3.4.3.12",
+        decode_oid_tlv(Sha3_512::ECDSA_SIGNATURE_OID)
+    );
+}
+
+#[test]
+fn shake128_digest_info() {
+    let (oid, null_parameters, digest) =
+        decode_digest_info(&Shake128::new().update(INPUT).digest_info::<49>());
+
+    assert_eq!("2.16.840.1.101.3.4.2.11", oid);
+    assert!(!null_parameters);
+    assert_eq!(
+        Shake128::new().update(INPUT).finalize::<32>().to_vec(),
+        digest
+    );
+    assert_eq!(oid, decode_oid_tlv(Shake128::OID));
+    assert_eq!(
+        "1.3.6.1.5.5.7.6.30",
+        decode_oid_tlv(Shake128::RSA_SIGNATURE_OID)
+    );
+    assert_eq!(
+        "1.3.6.1.5.5.7.6.32",
+        decode_oid_tlv(Shake128::ECDSA_SIGNATURE_OID)
+    );
+}
+
+#[test]
+fn shake256_digest_info() {
+    let (oid, null_parameters, digest) =
+        decode_digest_info(&Shake256::new().update(INPUT).digest_info::<81>());
+
+    assert_eq!("2.16.840.1.101.3.4.2.12", oid);
+    assert!(!null_parameters);
+    assert_eq!(
+        Shake256::new().update(INPUT).finalize::<64>().to_vec(),
+        digest
+    );
+    assert_eq!(oid, decode_oid_tlv(Shake256::OID));
+    assert_eq!(
+        "1.3.6.1.5.5.7.6.31",
+        decode_oid_tlv(Shake256::RSA_SIGNATURE_OID)
+    );
+    assert_eq!(
+        "1.3.6.1.5.5.7.6.33",
+        decode_oid_tlv(Shake256::ECDSA_SIGNATURE_OID)
+    );
+}
+
+#[test]
+#[should_panic(expected = "invalid DigestInfo length")]
+fn digest_info_wrong_length() {
+    Sha3_256::new().update(INPUT).digest_info::<32>();
+}
@@ -0,0 +1,106 @@
+//! The hashes of `hello` and `Hello World` are those of `hashMessage` in ethers
+//! and web3, the others were computed with a separate Python implementation of
+//! Keccak-256
+
+use keccak_const::address::Address;
+use keccak_const::eip191;
+use keccak_const::eip712;
+use keccak_const::Keccak256;
+
+#[test]
+fn personal_message_hash() {
+    const HELLO: [u8; 32] = eip191::personal_message_hash(b"hello");
+
+    assert_eq!(
+        [
+            0x50, 0xb2, 0xc4, 0x3f, 0xd3, 0x91, 0x06, 0xba, 0xfb, 0xba, 0x0d, 0xa3, 0x4f, 0xc4,
+            0x30, 0xe1, 0xf9, 0x1e, 0x3c, 0x96, 0xea, 0x2a, 0xce, 0xe2, 0xbc, 0x34, 0x11, 0x9f,
+            0x92, 0xb3, 0x77, 0x50,
+        ],
+        HELLO
+    );
+    assert_eq!(
+        [
+            0xa1, 0xde, 0x98, 0x86, 0x00, 0xa4, 0x2c, 0x4b, 0x4a, 0xb0, 0x89, 0xb6, 0x19, 0x29,
+            0x7c, 0x17, 0xd5, 0x3c, 0xff, 0xae, 0x5d, 0x51, 0x20, 0xd8, 0x2d, 0x8a, 0x92, 0xd0,
+            0xbb, 0x3b, 0x78, 0xf2,
+        ],
+        eip191::personal_message_hash(b"Hello World")
+    );
+}
+
+#[test]
+fn empty_personal_message() {
+    assert_eq!(
+        [
+            0x5f, 0x35, 0xdc, 0xe9, 0x8b, 0xa4, 0xfb, 0xa2, 0x55, 0x30, 0xa0, 0x26, 0xed, 0x80,
+            0xb2, 0xce, 0xcd, 0xaa, 0x31, 0x09, 0x1b, 0xa4, 0x95, 0x8b, 0x99, 0xb5, 0x2e, 0xa1,
+            0xd0, 0x68, 0xad, 0xad,
+        ],
+        eip191::personal_message_hash(b"")
+    );
+}
+
+#[test]
+fn long_personal_message() {
+    let mut message = [b'x'; 1027];
+    for (i, byte) in message[..1024].iter_mut().enumerate() {
+        *byte = i as u8;
+    }
+
+    assert_eq!(
+        [
+            0x08, 0x6f, 0x55, 0x30, 0x2b, 0x27, 0xcf, 0xd9, 0xe8, 0x34, 0xbf, 0x7f, 0xd3, 0x5d,
+            0x6d, 0xa5, 0xde, 0xf8, 0xd6, 0x42, 0xd0, 0x5e, 0x14, 0xdd, 0x33, 0xc7, 0x7b, 0x2b,
+            0x51, 0x9f, 0x62, 0x1e,
+        ],
+        eip191::personal_message_hash(&message)
+    );
+    assert_eq!(
+        eip191::personal_message_hash(&message),
+        message
+            .chunks(100)
+            .fold(eip191::personal_message(message.len()), |keccak, chunk| {
+                keccak.update(chunk)
+            })
+            .finalize()
+    );
+}
+
+#[test]
+fn validator_data_hash() {
+    const HASH: [u8; 32] = eip191::validator_data_hash(&Address([0xaa; 20]), b"data");
+
+    assert_eq!(
+        [
+            0x54, 0x20, 0x2b, 0x1c, 0x5f, 0xf9, 0x1e, 0x93, 0xab, 0x24, 0x46, 0x6e, 0x14, 0xd2,
+            0x10, 0x3d, 0xc2, 0x7f, 0x9d, 0xec, 0x7d, 0xa8, 0xe3, 0x31, 0x59, 0x6c, 0xeb, 0x71,
+            0xa8, 0x27, 0x95, 0x64,
+        ],
+        HASH
+    );
+    assert_eq!(
+        [
+            0x19, 0xd9, 0x72, 0xc7, 0x7a, 0x5f, 0x1e, 0x4d, 0x86, 0x42, 0xa9, 0x80, 0xba, 0xd1,
+            0x6a, 0xf8, 0xc4, 0x1d, 0x75, 0x2e, 0x55, 0xfd, 0xd7, 0x31, 0xed, 0x9c, 0x1d, 0x5e,
+            0xcd, 0x22, 0xba, 0xe6,
+        ],
+        eip191::validator_data_hash(&Address([0xaa; 20]), b"")
+    );
+}
+
+#[test]
+fn typed_data_hash() {
+    assert_eq!(
+        Keccak256::new()
+            .update(&[0x19, 0x01])
+            .update(&[0x11; 32])
+            .update(&[0x22; 32])
+            .finalize(),
+        eip191::typed_data_hash(&[0x11; 32], &[0x22; 32])
+    );
+    assert_eq!(
+        eip712::digest(&[0x11; 32], &[0x22; 32]),
+        eip191::typed_data_hash(&[0x11; 32], &[0x22; 32])
+    );
+}
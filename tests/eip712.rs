@@ -0,0 +1,281 @@
+//! The `Mail` values are those of the example in EIP-712 and the `Permit` type
+//! hash is `PERMIT_TYPEHASH` of EIP-2612 tokens, the others were computed with
+//! a separate Python implementation of Keccak-256
+
+use keccak_const::address::Address;
+use keccak_const::eip712;
+use keccak_const::eip712::EncodeType;
+use keccak_const::eip712::HashStruct;
+use keccak_const::solidity::indexed_address;
+use keccak_const::Keccak256;
+
+const MAIL_TYPES: [&str; 2] = [
+    "Mail(Person from,Person to,string contents)",
+    "Person(string name,address wallet)",
+];
+
+const COW: Address = Address([
+    0xcd, 0x2a, 0x3d, 0x9f, 0x93, 0x8e, 0x13, 0xcd, 0x94, 0x7e, 0xc0, 0x5a, 0xbc, 0x7f, 0xe7, 0x34,
+    0xdf, 0x8d, 0xd8, 0x26,
+]);
+
+const BOB: Address = Address([0xbb; 20]);
+
+const VERIFYING_CONTRACT: Address = Address([0xcc; 20]);
+
+#[test]
+fn mail_encode_type() {
+    const ENCODE_TYPE: EncodeType<128> = EncodeType::new(&MAIL_TYPES);
+
+    assert_eq!(
+        "Mail(Person from,Person to,string contents)Person(string name,address wallet)",
+        ENCODE_TYPE.as_str()
+    );
+    assert_eq!(ENCODE_TYPE.type_hash(), eip712::type_hash(&MAIL_TYPES));
+    assert_eq!(
+        [
+            0xa0, 0xce, 0xde, 0xb2, 0xdc, 0x28, 0x0b, 0xa3, 0x9b, 0x85, 0x75, 0x46, 0xd7, 0x4f,
+            0x55, 0x49, 0xc3, 0xa1, 0xd7, 0xbd, 0xc2, 0xdd, 0x96, 0xbf, 0x88, 0x1f, 0x76, 0x10,
+            0x8e, 0x23, 0xda, 0xc2,
+        ],
+        eip712::type_hash(&MAIL_TYPES)
+    );
+}
+
+#[test]
+fn mail_digest() {
+    const DOMAIN_SEPARATOR: [u8; 32] = eip712::domain_separator(
+        Some("Ether Mail"),
+        Some("1"),
+        Some(1),
+        Some(&VERIFYING_CONTRACT),
+        None,
+    );
+    const PERSON_TYPE_HASH: [u8; 32] = eip712::type_hash(&[MAIL_TYPES[1]]);
+    const MAIL: [u8; 32] = HashStruct::new(&eip712::type_hash(&MAIL_TYPES))
+        .push_struct(
+            &HashStruct::new(&PERSON_TYPE_HASH)
+                .push_string("Cow")
+                .push_address(&COW)
+                .finalize(),
+        )
+        .push_struct(
+            &HashStruct::new(&PERSON_TYPE_HASH)
+                .push_string("Bob")
+                .push_address(&BOB)
+                .finalize(),
+        )
+        .push_string("Hello, Bob!")
+        .finalize();
+
+    assert_eq!(
+        [
+            0xf2, 0xce, 0xe3, 0x75, 0xfa, 0x42, 0xb4, 0x21, 0x43, 0x80, 0x40, 0x25, 0xfc, 0x44,
+            0x9d, 0xea, 0xfd, 0x50, 0xcc, 0x03, 0x1c, 0xa2, 0x57, 0xe0, 0xb1, 0x94, 0xa6, 0x50,
+            0xa9, 0x12, 0x09, 0x0f,
+        ],
+        DOMAIN_SEPARATOR
+    );
+    assert_eq!(
+        [
+            0xc5, 0x2c, 0x0e, 0xe5, 0xd8, 0x42, 0x64, 0x47, 0x18, 0x06, 0x29, 0x0a, 0x3f, 0x2c,
+            0x4c, 0xec, 0xfc, 0x54, 0x90, 0x62, 0x6b, 0xf9, 0x12, 0xd0, 0x1f, 0x24, 0x0d, 0x7a,
+            0x27, 0x4b, 0x37, 0x1e,
+        ],
+        MAIL
+    );
+    assert_eq!(
+        [
+            0xbe, 0x60, 0x9a, 0xee, 0x34, 0x3f, 0xb3, 0xc4, 0xb2, 0x8e, 0x1d, 0xf9, 0xe6, 0x32,
+            0xfc, 0xa6, 0x4f, 0xcf, 0xae, 0xde, 0x20, 0xf0, 0x2e, 0x86, 0x24, 0x4e, 0xfd, 0xdf,
+            0x30, 0x95, 0x7b, 0xd2,
+        ],
+        eip712::digest(&DOMAIN_SEPARATOR, &MAIL)
+    );
+}
+
+#[test]
+fn permit_type_hash() {
+    const PERMIT_TYPE_HASH: [u8; 32] = eip712::type_hash(&[
+        "Permit(address owner,address spender,uint256 value,uint256 nonce,uint256 deadline)",
+    ]);
+
+    assert_eq!(
+        [
+            0x6e, 0x71, 0xed, 0xae, 0x12, 0xb1, 0xb9, 0x7f, 0x4d, 0x1f, 0x60, 0x37, 0x0f, 0xef,
+            0x10, 0x10, 0x5f, 0xa2, 0xfa, 0xae, 0x01, 0x26, 0x11, 0x4a, 0x16, 0x9c, 0x64, 0x84,
+            0x5d, 0x61, 0x26, 0xc9,
+        ],
+        PERMIT_TYPE_HASH
+    );
+}
+
+#[test]
+fn referenced_types_sorted() {
+    const TYPES: [&str; 6] = [
+        "Transaction(Person from,Person[] to,Asset[2] assets,Unused[] none)",
+        "Unrelated(uint256 value)",
+        "Person(string name,Wallet wallet)",
+        "Wallet(address addr,Asset[] holdings)",
+        "Asset(address token,uint256 amount)",
+        "Unused()",
+    ];
+    const TRANSACTION: EncodeType<256> = EncodeType::new(&TYPES);
+    const PERSON: EncodeType<256> = EncodeType::new(&[TYPES[2], TYPES[0], TYPES[3], TYPES[4]]);
+
+    assert_eq!(
+        concat!(
+            "Transaction(Person from,Person[] to,Asset[2] assets,Unused[] none)",
+            "Asset(address token,uint256 amount)",
+            "Person(string name,Wallet wallet)",
+            "Unused()",
+            "Wallet(address addr,Asset[] holdings)",
+        ),
+        TRANSACTION.as_str()
+    );
+    assert_eq!(
+        concat!(
+            "Person(string name,Wallet wallet)",
+            "Asset(address token,uint256 amount)",
+            "Wallet(address addr,Asset[] holdings)",
+        ),
+        PERSON.as_str()
+    );
+    assert_eq!(
+        Keccak256::new().update(PERSON.as_bytes()).finalize(),
+        eip712::type_hash(&[TYPES[2], TYPES[0], TYPES[3], TYPES[4]])
+    );
+}
+
+#[test]
+fn recursive_type() {
+    const TREE: EncodeType<64> =
+        EncodeType::new(&["Tree(Node root)", "Node(Node[] children,bytes32 value)"]);
+
+    assert_eq!(
+        "Tree(Node root)Node(Node[] children,bytes32 value)",
+        TREE.as_str()
+    );
+}
+
+#[test]
+fn member_types() {
+    const GROUP_TYPE_HASH: [u8; 32] = eip712::type_hash(&[
+        "Group(string name,address[] members,int8 rank,bool open,bytes data,bytes4 tag)",
+    ]);
+
+    assert_eq!(
+        [
+            0xbc, 0x70, 0x2c, 0x5a, 0x5d, 0xce, 0xce, 0x76, 0x2e, 0x99, 0x14, 0xc8, 0xf9, 0x4d,
+            0xcc, 0x5c, 0xbd, 0xe0, 0x9a, 0x98, 0x4f, 0xba, 0x0c, 0xdf, 0x8c, 0x18, 0xbd, 0xdf,
+            0x93, 0x4d, 0xcb, 0x7a,
+        ],
+        GROUP_TYPE_HASH
+    );
+    assert_eq!(
+        [
+            0x85, 0xe9, 0x54, 0x3f, 0x42, 0x25, 0x97, 0xb5, 0x26, 0x8a, 0x69, 0x83, 0x79, 0xb9,
+            0xa3, 0xa7, 0xc2, 0x58, 0x3b, 0x5b, 0x45, 0xf7, 0x36, 0x35, 0xe5, 0x03, 0xcf, 0x31,
+            0x92, 0xd1, 0x1e, 0xa0,
+        ],
+        HashStruct::new(&GROUP_TYPE_HASH)
+            .push_string("devs")
+            .push_array(&[
+                indexed_address(&Address([0x11; 20])),
+                indexed_address(&Address([0x22; 20])),
+            ])
+            .push_int::<8>(-2)
+            .push_bool(true)
+            .push_bytes(&[0x01, 0x02])
+            .push_bytes_n(&[0xde, 0xad, 0xbe, 0xef])
+            .finalize()
+    );
+}
+
+#[test]
+fn domain_separators() {
+    let full_type_hash = eip712::type_hash(&[
+        "EIP712Domain(string name,string version,uint256 chainId,address verifyingContract,bytes32 salt)",
+    ]);
+
+    assert_eq!(
+        HashStruct::new(&full_type_hash)
+            .push_string("Permit2")
+            .push_string("2")
+            .push_uint::<256>(42161)
+            .push_address(&VERIFYING_CONTRACT)
+            .push_word(&[0x5a; 32])
+            .finalize(),
+        eip712::domain_separator(
+            Some("Permit2"),
+            Some("2"),
+            Some(42161),
+            Some(&VERIFYING_CONTRACT),
+            Some(&[0x5a; 32]),
+        )
+    );
+    assert_eq!(
+        [
+            0x25, 0x66, 0x3b, 0x5c, 0x17, 0xcb, 0x1d, 0x31, 0x25, 0x35, 0x0f, 0xe8, 0xb5, 0xf3,
+            0xc3, 0x52, 0xf2, 0x5e, 0x75, 0x92, 0xba, 0x12, 0x19, 0x54, 0x96, 0xb7, 0x67, 0x07,
+            0xd8, 0x76, 0x26, 0x79,
+        ],
+        eip712::domain_separator(None, None, None, None, Some(&[0x5a; 32]))
+    );
+    assert_eq!(
+        [
+            0x61, 0x92, 0x10, 0x6f, 0x12, 0x9c, 0xe0, 0x5c, 0x90, 0x75, 0xd3, 0x19, 0xc1, 0xfa,
+            0x6e, 0xa9, 0xb3, 0xae, 0x37, 0xcb, 0xd0, 0xc1, 0xef, 0x92, 0xe2, 0xbe, 0x71, 0x37,
+            0xbb, 0x07, 0xba, 0xa1,
+        ],
+        eip712::domain_separator(None, None, None, None, None)
+    );
+}
+
+#[test]
+#[should_panic(expected = "encodeType exceeds the buffer")]
+fn encode_type_too_long() {
+    EncodeType::<64>::new(&MAIL_TYPES);
+}
+
+#[test]
+#[should_panic(expected = "unknown type")]
+fn unknown_member_type() {
+    eip712::type_hash(&["Mail(Person from,string contents)"]);
+}
+
+#[test]
+#[should_panic(expected = "`uint` must be written as `uint256`")]
+fn non_canonical_member_type() {
+    eip712::type_hash(&["Permit(address owner,uint value)"]);
+}
+
+#[test]
+#[should_panic(expected = "EIP-712 member types cannot be tuples")]
+fn tuple_member_type() {
+    eip712::type_hash(&["Order((address,uint256) maker)"]);
+}
+
+#[test]
+#[should_panic(expected = "EIP-712 member types cannot be tuples")]
+fn function_member_type() {
+    eip712::type_hash(&["Hook(function[] callbacks)"]);
+}
+
+#[test]
+#[should_panic(expected = "EIP-712 member types cannot be tuples")]
+fn fixed_member_type() {
+    eip712::type_hash(&["Rate(ufixed128x18 value)"]);
+}
+
+#[test]
+#[should_panic(expected = "invalid EIP-712 type definition")]
+fn missing_member_name() {
+    eip712::type_hash(&["Permit(address owner,uint256)"]);
+}
+
+#[test]
+#[should_panic(expected = "invalid EIP-712 type definition")]
+fn trailing_comma() {
+    eip712::type_hash(&["Permit(address owner,)"]);
+}
@@ -0,0 +1,213 @@
+//! Inputs follow RFC 9380 Appendix K, expected values were computed with a
+//! Python implementation of the RFC on top of the SHA-3 and SHAKE functions of
+//! `hashlib`
+
+use keccak_const::hash_to_curve::expand_message_xmd;
+use keccak_const::hash_to_curve::expand_message_xof_shake128;
+use keccak_const::hash_to_curve::expand_message_xof_shake256;
+
+const DST_SHAKE128: &[u8] = b"QUUX-V01-CS02-with-expander-SHAKE128";
+const DST_SHAKE256: &[u8] = b"QUUX-V01-CS02-with-expander-SHAKE256";
+const DST_SHA3_256: &[u8] = b"QUUX-V01-CS02-with-expander-SHA3-256";
+const LONG_DST: &[u8] = b"QUUX-V01-CS02-with-expander-SHAKE128-long-DST-111111111111111111111111111111111111111111111111111111111111111111111111111111111111111111111111111111111111111111111111111111111111111111111111111111111111111111111111111111111111111111111111111111111111111111111111111111111111";
+
+#[test]
+fn xof_shake128_empty() {
+    assert_eq!(
+        [
+            0x86, 0x51, 0x8c, 0x9c, 0xd8, 0x65, 0x81, 0x48, 0x6e, 0x94, 0x85, 0xaa, 0x74, 0xab,
+            0x35, 0xba, 0x15, 0x0d, 0x1c, 0x75, 0xc8, 0x8e, 0x26, 0xb7, 0x04, 0x3e, 0x44, 0xe2,
+            0xac, 0xd7, 0x35, 0xa2,
+        ],
+        expand_message_xof_shake128::<32>(b"", DST_SHAKE128),
+    );
+}
+
+#[test]
+fn xof_shake128_abc() {
+    assert_eq!(
+        [
+            0x86, 0x96, 0xaf, 0x52, 0xa4, 0xd8, 0x62, 0x41, 0x7c, 0x07, 0x63, 0x55, 0x60, 0x73,
+            0xf4, 0x7b, 0xc9, 0xb9, 0xba, 0x43, 0xc9, 0x9b, 0x50, 0x53, 0x05, 0xcb, 0x1e, 0xc0,
+            0x4a, 0x9a, 0xb4, 0x68,
+        ],
+        expand_message_xof_shake128::<32>(b"abc", DST_SHAKE128),
+    );
+}
+
+#[test]
+fn xof_shake128_abcdef() {
+    assert_eq!(
+        [
+            0x91, 0x2c, 0x58, 0xde, 0xac, 0x48, 0x21, 0xc3, 0x50, 0x9d, 0xbe, 0xfa, 0x09, 0x4d,
+            0xf5, 0x4b, 0x34, 0xb8, 0xf5, 0xd0, 0x1a, 0x19, 0x1d, 0x1d, 0x31, 0x08, 0xa2, 0xc8,
+            0x90, 0x77, 0xac, 0xca,
+        ],
+        expand_message_xof_shake128::<32>(b"abcdef0123456789", DST_SHAKE128),
+    );
+}
+
+#[test]
+fn xof_shake128_a512() {
+    assert_eq!(
+        [
+            0xdf, 0x34, 0x47, 0xcc, 0x5f, 0x3e, 0x9a, 0x77, 0xda, 0x10, 0xf8, 0x19, 0x21, 0x8d,
+            0xdf, 0x31, 0x34, 0x2c, 0x31, 0x07, 0x78, 0xe0, 0xe4, 0xef, 0x72, 0xbb, 0xae, 0xce,
+            0xe7, 0x86, 0xa4, 0xfe,
+        ],
+        expand_message_xof_shake128::<32>(
+            &[b"a512_".as_slice(), &[b'a'; 512]].concat(),
+            DST_SHAKE128
+        ),
+    );
+}
+
+#[test]
+fn xof_shake128_abc_128_bytes() {
+    assert_eq!(
+        [
+            0xc9, 0x52, 0xf0, 0xc8, 0xe5, 0x29, 0xca, 0x88, 0x24, 0xac, 0xc6, 0xa4, 0xca, 0xb0,
+            0xe7, 0x82, 0xfc, 0x36, 0x48, 0xc5, 0x63, 0xdd, 0xb0, 0x0d, 0xa7, 0x39, 0x9f, 0x2a,
+            0xe3, 0x56, 0x54, 0xf4, 0x86, 0x0e, 0xc6, 0x71, 0xdb, 0x23, 0x56, 0xba, 0x7b, 0xaa,
+            0x55, 0xa3, 0x4a, 0x9d, 0x7f, 0x79, 0x19, 0x7b, 0x60, 0xdd, 0xae, 0x6e, 0x64, 0x76,
+            0x8a, 0x37, 0xd6, 0x99, 0xa7, 0x83, 0x23, 0x49, 0x6d, 0xb3, 0x87, 0x8c, 0x8d, 0x64,
+            0xd9, 0x09, 0xd0, 0xf8, 0xa7, 0xde, 0x49, 0x27, 0xdc, 0xab, 0x0d, 0x3d, 0xbb, 0xc2,
+            0x6c, 0xb2, 0x0a, 0x49, 0xec, 0xeb, 0x05, 0x30, 0xb4, 0x31, 0xcd, 0xf4, 0x7b, 0xc8,
+            0xc0, 0xfa, 0x3e, 0x0d, 0x88, 0xf5, 0x3b, 0x31, 0x8b, 0x67, 0x39, 0xfb, 0xed, 0x7d,
+            0x76, 0x34, 0x97, 0x4f, 0x1b, 0x5c, 0x38, 0x6d, 0x62, 0x30, 0xc7, 0x62, 0x60, 0xd5,
+            0x33, 0x7a,
+        ],
+        expand_message_xof_shake128::<0x80>(b"abc", DST_SHAKE128),
+    );
+}
+
+#[test]
+fn xof_shake128_long_dst() {
+    assert_eq!(
+        [
+            0x0a, 0xec, 0x29, 0xbd, 0xa8, 0xde, 0xce, 0x92, 0x3a, 0xaf, 0xce, 0x62, 0xc2, 0xb8,
+            0x34, 0xc4, 0xdf, 0x22, 0x96, 0x78, 0xbd, 0xfc, 0x54, 0xcd, 0x6e, 0x34, 0xce, 0xde,
+            0xd6, 0xba, 0xb1, 0xc1,
+        ],
+        expand_message_xof_shake128::<32>(b"abc", LONG_DST),
+    );
+}
+
+#[test]
+fn xof_shake256_empty() {
+    assert_eq!(
+        [
+            0x2f, 0xfc, 0x05, 0xc4, 0x8e, 0xd3, 0x2b, 0x95, 0xd7, 0x2e, 0x80, 0x7f, 0x6e, 0xab,
+            0x9f, 0x75, 0x30, 0xdd, 0x1c, 0x2f, 0x01, 0x39, 0x14, 0xc8, 0xfe, 0xd3, 0x8c, 0x5c,
+            0xcc, 0x15, 0xad, 0x76,
+        ],
+        expand_message_xof_shake256::<32>(b"", DST_SHAKE256),
+    );
+}
+
+#[test]
+fn xof_shake256_abc() {
+    assert_eq!(
+        [
+            0xb3, 0x9e, 0x49, 0x38, 0x67, 0xe2, 0x76, 0x72, 0x16, 0x79, 0x2a, 0xbc, 0xe1, 0xf2,
+            0x67, 0x6c, 0x19, 0x7c, 0x06, 0x92, 0xae, 0xd0, 0x61, 0x56, 0x0e, 0xad, 0x25, 0x18,
+            0x21, 0x80, 0x8e, 0x07,
+        ],
+        expand_message_xof_shake256::<32>(b"abc", DST_SHAKE256),
+    );
+}
+
+#[test]
+fn xof_shake256_long_dst() {
+    assert_eq!(
+        [
+            0x26, 0xf8, 0x2f, 0xbd, 0xdb, 0x59, 0xa1, 0x1e, 0x7b, 0x2f, 0x34, 0x8b, 0xfc, 0x3d,
+            0xe6, 0x8c, 0xe1, 0xb0, 0x43, 0xf8, 0x4e, 0xb1, 0xe3, 0xc4, 0x35, 0xec, 0xbd, 0xb8,
+            0x98, 0xb4, 0x50, 0x98,
+        ],
+        expand_message_xof_shake256::<32>(b"", LONG_DST),
+    );
+}
+
+#[test]
+fn xmd_sha3_256_empty() {
+    assert_eq!(
+        [
+            0x06, 0x33, 0xe7, 0xab, 0xc9, 0x09, 0x82, 0x28, 0xc7, 0x49, 0xe7, 0xcc, 0x1c, 0x08,
+            0xf7, 0xc2, 0x80, 0x67, 0xa0, 0x05, 0xdf, 0x8b, 0x21, 0xce, 0x2f, 0x87, 0x7e, 0x15,
+            0x75, 0x43, 0x59, 0x3c,
+        ],
+        expand_message_xmd::<32>(b"", DST_SHA3_256),
+    );
+}
+
+#[test]
+fn xmd_sha3_256_abc() {
+    assert_eq!(
+        [
+            0xdd, 0x81, 0x22, 0x23, 0x40, 0xc0, 0xb0, 0x6f, 0x53, 0x92, 0x1e, 0xee, 0x61, 0xce,
+            0xd0, 0xee, 0x3b, 0x54, 0x2b, 0xb2, 0xdb, 0xde, 0x6b, 0xa6, 0xff, 0xa6, 0x2f, 0xb9,
+            0xab, 0x37, 0x21, 0x63,
+        ],
+        expand_message_xmd::<32>(b"abc", DST_SHA3_256),
+    );
+}
+
+#[test]
+fn xmd_sha3_256_abc_128_bytes() {
+    assert_eq!(
+        [
+            0xd3, 0x23, 0x5a, 0xd9, 0x7d, 0xf2, 0xcf, 0x04, 0x02, 0xdc, 0x75, 0xc1, 0x37, 0x33,
+            0x51, 0xf2, 0x30, 0xa2, 0x3f, 0xad, 0x13, 0x5b, 0xb5, 0x52, 0xfc, 0x22, 0xe5, 0x72,
+            0xe1, 0xeb, 0xbc, 0x9e, 0x26, 0xf4, 0x69, 0x2d, 0x8c, 0xb2, 0xbf, 0x1b, 0x8b, 0xa1,
+            0x6a, 0x22, 0x37, 0x1e, 0xa4, 0x90, 0xac, 0x8e, 0x83, 0xa7, 0xd5, 0x80, 0xf8, 0x0b,
+            0x3c, 0x65, 0x59, 0x8b, 0x91, 0x0c, 0x47, 0xe4, 0xa6, 0xbc, 0x4a, 0x90, 0x4c, 0xbe,
+            0x21, 0xdd, 0x3e, 0xd6, 0x08, 0x83, 0xe7, 0x63, 0x5f, 0x4f, 0x30, 0x45, 0xfc, 0xe9,
+            0x9d, 0xf4, 0x8f, 0xd0, 0x19, 0x5c, 0x84, 0x57, 0x40, 0x5f, 0xe4, 0x96, 0x97, 0xde,
+            0x58, 0x9b, 0xca, 0x6c, 0xdd, 0x9a, 0xf9, 0x10, 0x63, 0x64, 0x3d, 0x9c, 0x64, 0xca,
+            0xab, 0x9e, 0x63, 0xec, 0x06, 0x58, 0x26, 0x7c, 0xda, 0x21, 0xbe, 0x35, 0xac, 0xe6,
+            0xf2, 0x0f,
+        ],
+        expand_message_xmd::<0x80>(b"abc", DST_SHA3_256),
+    );
+}
+
+#[test]
+fn xmd_sha3_256_abc_45_bytes() {
+    assert_eq!(
+        [
+            0xa2, 0xeb, 0x7c, 0x8c, 0x85, 0x92, 0x6c, 0x72, 0x45, 0x10, 0xd3, 0x59, 0x4e, 0x83,
+            0x35, 0x08, 0xd2, 0xbc, 0x2e, 0x29, 0xfc, 0x4d, 0x77, 0xcf, 0x91, 0xb9, 0x6f, 0x52,
+            0x1b, 0x02, 0xfa, 0x9c, 0x62, 0xe7, 0x3c, 0xcd, 0x6e, 0xd4, 0xf2, 0x36, 0x6e, 0xb2,
+            0xd1, 0x4a, 0xe3,
+        ],
+        expand_message_xmd::<45>(b"abc", DST_SHA3_256),
+    );
+}
+
+#[test]
+fn xmd_sha3_256_long_dst() {
+    assert_eq!(
+        [
+            0x02, 0x9d, 0x56, 0x8d, 0xda, 0x33, 0x30, 0xe9, 0x57, 0xcb, 0xdf, 0x01, 0xca, 0xe1,
+            0x07, 0x1c, 0x2a, 0xb9, 0x86, 0x85, 0x60, 0x37, 0xd4, 0x0a, 0x50, 0x51, 0x9d, 0x51,
+            0xc5, 0x17, 0x9d, 0xf3,
+        ],
+        expand_message_xmd::<32>(b"abc", LONG_DST),
+    );
+}
+
+#[test]
+fn const_expand_message() {
+    const XOF: [u8; 48] = expand_message_xof_shake256(b"abc", DST_SHAKE256);
+    const XMD: [u8; 48] = expand_message_xmd(b"abc", DST_SHA3_256);
+
+    assert_eq!(expand_message_xof_shake256::<48>(b"abc", DST_SHAKE256), XOF);
+    assert_eq!(expand_message_xmd::<48>(b"abc", DST_SHA3_256), XMD);
+}
+
+#[test]
+#[should_panic(expected = "requested output too long")]
+fn xmd_output_too_long() {
+    expand_message_xmd::<{ 255 * 32 + 1 }>(b"abc", DST_SHA3_256);
+}
@@ -0,0 +1,287 @@
+//! The keys and signatures were generated with a separate Python signer,
+//! written from RFC 8554 on top of `hashlib`, for the SHAKE256 parameter sets
+//! of NIST SP 800-208. The test cases of RFC 8554 only use SHA-256 parameter
+//! sets, which this module does not implement.
+
+mod common;
+
+use keccak_const::lms;
+use keccak_const::lms::LmotsAlgorithm;
+use keccak_const::lms::LmsAlgorithm;
+
+const MESSAGE: &[u8] = b"The quick brown fox jumps over the lazy dog";
+
+const ID: [u8; 16] = [
+    0xd0, 0xd1, 0xd2, 0xd3, 0xd4, 0xd5, 0xd6, 0xd7, 0xd8, 0xd9, 0xda, 0xdb, 0xdc, 0xdd, 0xde, 0xdf,
+];
+
+const SEED: [u8; 24] = [
+    0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0a, 0x0b, 0x0c, 0x0d, 0x0e, 0x0f,
+    0x10, 0x11, 0x12, 0x13, 0x14, 0x15, 0x16, 0x17,
+];
+
+/// `LMS_SHAKE_M24_H5` public key with `LMOTS_SHAKE_N24_W4`
+const LMS_PUBLIC_KEY: &str = "0000001400000017d0d1d2d3d4d5d6d7d8d9dadbdcdddedffe9deac3b4f60dfdb65edcfdf3b671c2cc9f1746a4385a35";
+
+/// Signature of [`MESSAGE`] with leaf 9 of [`LMS_PUBLIC_KEY`]
+const LMS_SIGNATURE: &str = concat!(
+    "0000000900000017c0c1c2c3c4c5c6c7c8c9cacbcccdcecfc0c1c2c3c4c5c6c77cb030dd10c4f0fd9246161fdb00e203",
+    "0cba7c6e8c1ae1fe67a92320550b2b3ff9878eab628a36e62effa648d96bba7b81afde9a36ac5af4ede8e9e87d195baf",
+    "c9bce54882c8cd36dd2b71159397c8c11db0ca61afdcb599b9fba5b01def646dfedc505b21487004893872420005af20",
+    "5a6675eab199813ac8b2fa8fa5aef3fe6c4e831a472a359202eca147dd6ca20573c9e31c0d7179fd466d380565392dcd",
+    "8de209dbf36aaa0a5fe7c4f5bdf34bef84f4b9c43c41e71f00e81319c3dee282b0c27d3f547308bf71429bebec6cadc4",
+    "646d7feee47adf15cd307ec82f06f4779c2bca3ad6147e7f915381f50bab13fac163465f6eb2cadf1877c9bd081ca617",
+    "27f4989cc3a87e4588fd39c00528408b8e6b73a770d7cfa8ea9d1524e7c5d03b16ac21c9f28ab564be3270fe5c3e0ea1",
+    "1a22989e7fdb978f6417b0ccb8f34b0a2071c0c1ef465a5823d4ae26b4947d7f766127860b48f2216f495ee15a1e78bd",
+    "2589c166190b94b4854999fd30908de01330fd28167305aab79a544de6755faafe15ab11df9e29dbe216bac4f1396f17",
+    "dc0262fb1c4de793ae538e04c6d9ab98aaf289e3598b22668adcdf83b71434d71fa5780427c6c130fbab4e9f7294d78d",
+    "55e6a074a4f1082cff058f3fdd0b25417b0942d085c5e44ec3557ee42f4cd1d3c4829ac0480bc9e052f2f619a39f145a",
+    "c4b030aeb3a45b6152716f499c451d99c8dcd362a00da587cfe620b5c3c2bfafc97e8617bbda8fcbce8bb68c4dab43a7",
+    "0fa76805b300ced253f30623c1d7533e774414a02f98a0c8686152437c5f777b2727f69909b4f7bb6dbe9ac6b38b12c1",
+    "ae8fcfa78a5890223cda7129bee619d59fda48b403f0474a2e2705cfff4dd932e4f00a1e5b81468fabc9c16e68c4f252",
+    "4d44833e86523c9a73c155ed29adc665981629338ffba66b12ff9247999636fdc4c4444d8c38df682f0a0fab0333cd80",
+    "2194ebcf3914e105dfa02d3238cf01aaa3459e6e6db55542566f44132bc9527d9a4e3f74e23dd7121989e0d44c981e44",
+    "c432cdd7fc069c477c3ee1aa3a9e361088c7587926b10dff17dd2a6b1c5f5513c898424411641ea4e03f24a3d1730c00",
+    "afc8a3ddd61b2dff2d3ec3346ecc752abcb564e146e6fe649c8913f21c05b8056a659e02a21c172331a302993450e8a0",
+    "f5752b5c7d12aba81d8c4b9ecd4391d84749ab58c0bc3f984c60f41f928dd0376b42ab6945ac9fc2a9a7d3ec7f307772",
+    "cc2c78b2ca74e4684c58c6b71336e1ccdd742a05fa1c202889813026314d9334a93f59ecf990e175426bde4f74ead6ca",
+    "a74e39e1bef8c86be6a66f1f04955b09c6c3451cf5f244ceb6d59d5fc4bb44a452c56f38b572a94b7226bbf16cf85b6e",
+    "16465c40bd5f6a8f7d44ebf227079f5665f0e72b9328203aeb524f1d2f18f64e7e16a22674065ff830635eab359fc14c",
+    "ed6e2bfec38166c991518c2f55e7f1455daad6fdcca91dbaa32a6450f86f329f4a1cee959faf1e055d46ad4394ed6c5d",
+    "d3a564caec00f6318ab968f3cadd413dd372eba3a2e8d7ba5ef7d6258531539a6864a34af11ad2823c71a90ed8be6f8b",
+    "f5f3bf937bab4f25cb9d8d85eb0e4d91db5febfba3c1c5ffdb77d633f24857b02bf920c1a61df072ac65394ba6a13305",
+    "35db51495b6c26bbbd762cb3dd41fe677f8591ef6f24ce6cdf0b17be167204cecd72e7804f5870d7cbd29f17c1d9b3f5",
+    "49d535c6d947cf1e000000143a68b8255f1206b225e14c8cb7ae94414851c5d6a053e7b12dc6233656e33a2032822c25",
+    "eaa64165f4c67c29cb3227e300268dbe8320b8657ed9a9032934494edc1d279b97d6526a4232691bcdaa83a505b68bc5",
+    "ebbf59e9a43d05c787f5bd96781ee53f36974dac9ce8b79387de239232f522655b80f4ed",
+);
+
+/// `LMS_SHAKE_M24_H5` public key with `LMOTS_SHAKE_N24_W1`
+const LMS_W1_PUBLIC_KEY: &str = "0000001400000015d0d1d2d3d4d5d6d7d8d9dadbdcdddedf949f6d8f3e3143a002f4bcfce683c8348bb4ce0b7aa2a206";
+
+/// Two level HSS public key with `LMS_SHAKE_M32_H5`/`LMOTS_SHAKE_N32_W8` at
+/// the top level
+const HSS_PUBLIC_KEY: &str = concat!(
+    "000000020000000f00000014101112131415161718191a1b1c1d1e1f2242027677bc593d3433e96182fd89a6b10d37a1",
+    "e98baf3a7d09a8490ff03dad",
+);
+
+/// Signature of [`MESSAGE`] with [`HSS_PUBLIC_KEY`], the bottom level using
+/// `LMS_SHAKE_M24_H5`/`LMOTS_SHAKE_N24_W8`
+const HSS_SIGNATURE: &str = concat!(
+    "000000010000000300000014aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaabec361bb",
+    "06e787bc1342a260b1b67a8f81627fc694c69b5bd5de002898bb4ff491444e8700984f1922f5a7d418d06acc6abc792d",
+    "1ab6cab64853407fa97036e668591fbc73e124a24e2817ff486f5146e02dff41c349f3a8c0c6e8566ed253869682a9c5",
+    "c59d16f2d24631b0770ee9863ce07b4424b465e64c7f4518430d49d49cef7737aeabddd90df992daa90788a79948359e",
+    "652018c6b9deda161efbe93575abb2c2ea9c621270ba48bb7b682d31079967e9f0aa25648172c9bd628d2841445b76c5",
+    "fe906976db9b76aebdbfde12110be77341ea5bc9bb33c1642f871cfde682389fa3943dbe48a41fbdae04316b140e9447",
+    "0e7e8d21b6fe7a4fd75a921fc7139cca406377bb7d22d0fa083426b1100f36e31d4c5bb0fe1ce0f76421fdb8fde75747",
+    "d2a301bd0198f8530092d5f2c8047138636bc56a03dbc04456c28db4d19e5c17d036313dd6ed6e9dda867bf21560b131",
+    "14bc2ace43a23fbc6118482e9c79d94a0f15d88ce0915ec1b8f46f216eaa0925548ade1dc467fce5ebcc12964f8a6a0e",
+    "2bcb5248a363dcd8cd0e7a92f18d0d89f2aada2156238af3fd91f6b5cf207f598367a4c732bbc201a2a77dff835931b5",
+    "8e5f212fc23deda9bceb8c53ed8cd4035390f3314eb3bd61c3f380087ffd9eabcd44c6c5ed5b15e3ca036934b22072fe",
+    "8692b94643655097706884e129d71fcaa8d5aebd76609d4d299481aeb23b3f517b036e17c304a3a257f88777fc195312",
+    "d6e52bb41dfbf0291060718b23a6645f27a60b69fa8171274af5d261e4ebd2b8f1c821ec9e4efe19a45a75f1a8ea8855",
+    "6bf75c548d61b3970ec72b8a32587266c2a28393ae0948fce86ba847b5d1f6cf80fe3dd65517a5a8d9335a49868587eb",
+    "da33c0037df60fb85215d0571504f0bce5dc344194b04c95a3d974a70d18a78691fbb57014764bbfa9c0671f5f5d258f",
+    "66dce43c6777ff21a6de2987bb044bdc6cac1ae77a5dbd39a591af392b46c939386ce49e16e3b57c7a8a7c1f0208129b",
+    "8c02f9ae28ae21ee3e537252f7cc94f1bbf31a702dd63781a00a3654d36e024bd15b38234b0bffbd7c0b9101b500fced",
+    "40d4b8b15970f02ed45eb043b220bafaa2a38afae6b34e6221446289b56b6139b16167f9cf29605aeb5fbf0fa3e954bd",
+    "e9eb85e69113bed102c22478cbbbf0517725732eaa92779346696a96935685e9fd1a87654abc8a8e4548762e672c1ba1",
+    "cd51b0eab73808c41b2e791d883e7d75efefda8ad55febaee072bd6393379049d86cfdd1eec5b5ba06020923b2c80d3f",
+    "027bd896fc21741db89e6cedd428960adf1da8764f1b9587b5aa3036cbcb1f8b0350472d9f2fe7b8ba8be1e7041982b9",
+    "2e2bd32c584df14d8ac9d9f2a54d387f2e40e7730503053ab5e48f385a6adb43c58b39b26035b67ffa97a4c4ddfdd07a",
+    "22ee87a7b1506ba68bdc8a5e9c69c40e631d463da2cc54f57f1634aa84ae175c1b7a34e880b3e20db9471c60148a954f",
+    "e289b7b43c749dc1080b2e8db8fe84359b7887bd2ae3a6971755e4310000000f638b2cddb82055b7732f6f7b25ff5d3a",
+    "06ab826cee499a4fffc09b95d2de2125a6ef7d50569262477d89b15334b68b21b9f22685a34142ac18cbcc5c8f9792c8",
+    "caf2c42cfc52cfe718a1add87c9e80cf01bf033cebbb49f8af01142941f75b06603560c04c77945da44ae1ad6d4a1482",
+    "c545ebf7d215f3ad13c04ebd0d55542fbc24738c1e4cbcf628ef0609436f8427c3027d290e1769b644b3c45c002f8c70",
+    "0000001400000018303132333435363738393a3b3c3d3e3f91c4d92d15814a2292565ac1259150260ad6579da2de2257",
+    "0000001e00000018bbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbb6b172978d54faff1904b54eee8269514",
+    "6024f70ec0f967b91689a9417d35dab765debcf592d8fcacdd487b5522ad3d3c25a19ff250933ed4800c3427b49f2ea5",
+    "049f0ed8317e24b756eab588df6431f0f8f07d5762c4f3d8375c1a285d3a18b3c138506e28d80fc01f025b0eb2cc4abe",
+    "0247c80c3d16fc53c08e1f69450a94d6b640ea0536b01bb1509544daf5d117710ae3798f1d5b3c4597d6676ddaea4502",
+    "d0488886564a5038ee0f49eb1efc86cf01157d88124aed41cb74c1a0c23d7b07d9ae7eeb6e7ecb66db9d82408cd544ea",
+    "d44e9dff63b4301c6dc1abd3d469b8f2955e80f8fec54b3084fdeae8ec51c0e6653d27e8f50a801facc09905ca57ed5b",
+    "515a952447d02188d22c4e231f5e212c81209fd62ba8ab14e24b8d40c013bce13f76b97d081463c8ce9637ed56f81f90",
+    "22f96dfae2f339b23551df7a45cec844cee8bde33bfd23321f0e694832b32e4cceae96fa9a583439e225c60eb0c83170",
+    "8c9eb28b490e0f9e9605711b4ce43bb4e5ae8100c03ae5a54f7c194ab96af2a2e45b47fb91eef893ae5e3f03477f8f08",
+    "dd86120dba1450bfb9d4d3af3b0b56553f3c728d711a36154843fbf437cbc83a49d40cf80b509cbce41ed84febc797cd",
+    "9010f9bc90e2b679b8ad8ba54d3ad1feb454eb64f781bec0ec61398f0018d3b0080f193e5b18e193eeca0a5b2a23ba4c",
+    "4c5b172099870d630517d1f68c450e8dc0c8f24f5468e7141a4f885bc84479cebee14077e5534fa57137e93bb0ddc7af",
+    "e0727474df48c89bb1d4b298dd8ae3db5122033e0747d46d4f6925252c03e0f3f59c86ebca4f91651aab39b3cf8a3b23",
+    "a1490d1baeaefb232e61d83b13f336751444cefd2303771072690fb9f85edb0f000000141132e84b70cbbd13ed246913",
+    "b3bf1d53bfe149de00649f17a7a718ec2a63532ddbdd1a2a72ac21d81a9ba059331f89345035294a4e540611ebbf499a",
+    "3c7341d08a7f11a89e6320094db3e2b6b62e4492181e6ad790ad9d05575ad51c699d1e395da4b0ced1aea85e58205d93",
+    "bcd232429485663a4dcefd86",
+);
+
+#[test]
+fn parameter_sets() {
+    for (lmots, n, w, p, ls) in [
+        (LmotsAlgorithm::ShakeN32W1, 32, 1, 265, 7),
+        (LmotsAlgorithm::ShakeN32W2, 32, 2, 133, 6),
+        (LmotsAlgorithm::ShakeN32W4, 32, 4, 67, 4),
+        (LmotsAlgorithm::ShakeN32W8, 32, 8, 34, 0),
+        (LmotsAlgorithm::ShakeN24W1, 24, 1, 200, 8),
+        (LmotsAlgorithm::ShakeN24W2, 24, 2, 101, 6),
+        (LmotsAlgorithm::ShakeN24W4, 24, 4, 51, 4),
+        (LmotsAlgorithm::ShakeN24W8, 24, 8, 26, 0),
+    ] {
+        assert_eq!(Some(lmots), LmotsAlgorithm::from_u32(lmots as u32));
+        assert_eq!((n, w, p, ls), (lmots.n(), lmots.w(), lmots.p(), lmots.ls()));
+    }
+    for (lms, m, h) in [
+        (LmsAlgorithm::ShakeM32H5, 32, 5),
+        (LmsAlgorithm::ShakeM32H25, 32, 25),
+        (LmsAlgorithm::ShakeM24H5, 24, 5),
+        (LmsAlgorithm::ShakeM24H10, 24, 10),
+        (LmsAlgorithm::ShakeM24H25, 24, 25),
+    ] {
+        assert_eq!(Some(lms), LmsAlgorithm::from_u32(lms as u32));
+        assert_eq!((m, h), (lms.m(), lms.h()));
+    }
+    assert_eq!(None, LmotsAlgorithm::from_u32(0x04));
+    assert_eq!(None, LmsAlgorithm::from_u32(0x05));
+}
+
+#[test]
+fn lms_public_key() {
+    let public_key = lms::public_key::<{ LmsAlgorithm::ShakeM24H5.public_key_len() }>(
+        LmsAlgorithm::ShakeM24H5,
+        LmotsAlgorithm::ShakeN24W4,
+        &ID,
+        &SEED,
+    );
+
+    assert_eq!(common::hex_bytes(LMS_PUBLIC_KEY), public_key);
+}
+
+#[test]
+fn lms_w1_public_key() {
+    let public_key = lms::public_key::<48>(
+        LmsAlgorithm::ShakeM24H5,
+        LmotsAlgorithm::ShakeN24W1,
+        &ID,
+        &SEED,
+    );
+
+    assert_eq!(common::hex_bytes(LMS_W1_PUBLIC_KEY), public_key);
+}
+
+#[test]
+#[should_panic(expected = "mismatched LMS and LM-OTS parameter sets")]
+fn lms_public_key_mismatched_parameter_sets() {
+    lms::public_key::<48>(
+        LmsAlgorithm::ShakeM24H5,
+        LmotsAlgorithm::ShakeN32W8,
+        &ID,
+        &SEED,
+    );
+}
+
+#[test]
+fn lms_verify() {
+    let public_key = common::hex_bytes(LMS_PUBLIC_KEY);
+    let signature = common::hex_bytes(LMS_SIGNATURE);
+
+    assert_eq!(
+        LmsAlgorithm::ShakeM24H5.signature_len(LmotsAlgorithm::ShakeN24W4),
+        signature.len()
+    );
+    assert!(lms::verify(&public_key, MESSAGE, &signature));
+}
+
+#[test]
+fn lms_verify_wrong_message() {
+    let public_key = common::hex_bytes(LMS_PUBLIC_KEY);
+    let signature = common::hex_bytes(LMS_SIGNATURE);
+
+    assert!(!lms::verify(
+        &public_key,
+        b"The quick brown fox",
+        &signature
+    ));
+}
+
+#[test]
+fn lms_verify_corrupted_signature() {
+    let public_key = common::hex_bytes(LMS_PUBLIC_KEY);
+    let signature = common::hex_bytes(LMS_SIGNATURE);
+
+    for i in [0, 3, 7, 8, 40, signature.len() - 130, signature.len() - 1] {
+        let mut corrupted = signature.clone();
+        corrupted[i] ^= 1;
+        assert!(!lms::verify(&public_key, MESSAGE, &corrupted), "{i}");
+    }
+    assert!(!lms::verify(
+        &public_key,
+        MESSAGE,
+        &signature[..signature.len() - 1]
+    ));
+    assert!(!lms::verify(&public_key, MESSAGE, &[]));
+}
+
+#[test]
+fn lms_verify_corrupted_public_key() {
+    let public_key = common::hex_bytes(LMS_PUBLIC_KEY);
+    let signature = common::hex_bytes(LMS_SIGNATURE);
+
+    for i in [3, 7, 8, public_key.len() - 1] {
+        let mut corrupted = public_key.clone();
+        corrupted[i] ^= 1;
+        assert!(!lms::verify(&corrupted, MESSAGE, &signature), "{i}");
+    }
+}
+
+#[test]
+fn hss_verify() {
+    let public_key = common::hex_bytes(HSS_PUBLIC_KEY);
+    let signature = common::hex_bytes(HSS_SIGNATURE);
+
+    assert!(lms::hss_verify(&public_key, MESSAGE, &signature));
+    assert!(!lms::hss_verify(&public_key, b"message", &signature));
+}
+
+#[test]
+fn hss_verify_corrupted_signature() {
+    let public_key = common::hex_bytes(HSS_PUBLIC_KEY);
+    let signature = common::hex_bytes(HSS_SIGNATURE);
+
+    // covers Nspk, the top level signature, the signed public key and the
+    // bottom level signature
+    for i in [3, 100, 1300, 1330, 2000, signature.len() - 1] {
+        let mut corrupted = signature.clone();
+        corrupted[i] ^= 1;
+        assert!(!lms::hss_verify(&public_key, MESSAGE, &corrupted), "{i}");
+    }
+    assert!(!lms::hss_verify(
+        &public_key,
+        MESSAGE,
+        &signature[..signature.len() - 1]
+    ));
+}
+
+#[test]
+fn hss_verify_wrong_levels() {
+    let mut public_key = common::hex_bytes(HSS_PUBLIC_KEY);
+    let signature = common::hex_bytes(HSS_SIGNATURE);
+    public_key[3] = 1;
+
+    assert!(!lms::hss_verify(&public_key, MESSAGE, &signature));
+    assert!(!lms::hss_verify(&public_key[4..], MESSAGE, &signature));
+}
+
+#[test]
+fn hss_verify_maximum_nspk() {
+    let mut signature = common::hex_bytes(HSS_SIGNATURE);
+    signature[..4].copy_from_slice(&u32::MAX.to_be_bytes());
+
+    assert!(!lms::hss_verify(&[0, 0, 0, 1], MESSAGE, &signature));
+    assert!(!lms::hss_verify(&[0, 0, 0, 1], b"m", &[0xff; 8]));
+    assert!(!lms::hss_verify(
+        &common::hex_bytes(HSS_PUBLIC_KEY),
+        MESSAGE,
+        &signature
+    ));
+}
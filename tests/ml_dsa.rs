@@ -0,0 +1,263 @@
+//! Expected values were computed with a separate Python implementation of
+//! FIPS 204 on top of `hashlib`. Its key generation gives the public keys of
+//! OpenSSL 3.5 for the seed of `key_generation`, whose expected hashes are
+//! those of the OpenSSL keys.
+
+mod common;
+
+use keccak_const::ml_dsa;
+use keccak_const::ml_dsa::ml_dsa_44;
+use keccak_const::ml_dsa::ml_dsa_65;
+use keccak_const::ml_dsa::ml_dsa_87;
+use keccak_const::Shake256;
+
+const RHO: [u8; 32] = seed();
+const RHO_PRIME: [u8; 64] = seed();
+
+const fn seed<const N: usize>() -> [u8; N] {
+    let mut seed = [0; N];
+    let mut i = 0;
+    while i < N {
+        seed[i] = i as u8;
+        i += 1;
+    }
+    seed
+}
+
+fn sum(poly: &[i32; 256]) -> i64 {
+    poly.iter().map(|&c| i64::from(c)).sum()
+}
+
+#[test]
+fn ml_dsa_44_expand_a() {
+    const A_HAT: [[[i32; 256]; ml_dsa_44::L]; ml_dsa_44::K] = ml_dsa_44::expand_a(&RHO);
+    let poly = &A_HAT[3][2];
+
+    assert_eq!(
+        [6894153, 1955557, 2339092, 4773143, 5813936, 1011111],
+        poly[..6]
+    );
+    assert_eq!(7469660, poly[255]);
+    assert_eq!(1071131532, sum(poly));
+}
+
+#[test]
+fn ml_dsa_87_expand_a() {
+    let a_hat = ml_dsa_87::expand_a(&RHO);
+    let poly = &a_hat[7][6];
+
+    assert_eq!(
+        [3685217, 4496038, 4649711, 5365102, 4046445, 4086218],
+        poly[..6]
+    );
+    assert_eq!(595249, poly[255]);
+    assert_eq!(1081270640, sum(poly));
+}
+
+#[test]
+fn ml_dsa_44_expand_s() {
+    let (s1, s2) = ml_dsa_44::expand_s(&RHO_PRIME);
+
+    assert_eq!([1, 2, 2, 1, -2, 2], s1[0][..6]);
+    assert_eq!(-2, s1[0][255]);
+    assert_eq!(47, sum(&s1[0]));
+    assert_eq!([-2, 2, -2, 2, 0, 0], s2[3][..6]);
+    assert_eq!(0, s2[3][255]);
+    assert_eq!(37, sum(&s2[3]));
+    assert!(s1
+        .iter()
+        .chain(&s2)
+        .flatten()
+        .all(|c| c.abs() <= ml_dsa_44::ETA));
+}
+
+#[test]
+fn ml_dsa_65_expand_s() {
+    let (s1, s2) = ml_dsa_65::expand_s(&RHO_PRIME);
+
+    assert_eq!([-4, -4, 4, -4, 1, 2], s1[4][..6]);
+    assert_eq!(2, s1[4][255]);
+    assert_eq!(12, sum(&s1[4]));
+    assert_eq!([0, 3, 0, -1, -3, 2], s2[0][..6]);
+    assert_eq!(1, s2[0][255]);
+    assert_eq!(52, sum(&s2[0]));
+    assert!(s1
+        .iter()
+        .chain(&s2)
+        .flatten()
+        .all(|c| c.abs() <= ml_dsa_65::ETA));
+}
+
+#[test]
+fn ml_dsa_44_expand_mask() {
+    let y = ml_dsa_44::expand_mask(&RHO_PRIME, 7);
+
+    assert_eq!([86354, -9995, -21271, -73982, 13880, -38569], y[0][..6]);
+    assert_eq!(-6800, y[0][255]);
+    assert_eq!(-1098036, sum(&y[0]));
+    assert_eq!([-67536, -81998, 122121, -82369, -73706, 61667], y[3][..6]);
+    assert_eq!(-99883, y[3][255]);
+    assert_eq!(-133367, sum(&y[3]));
+    assert!(y
+        .iter()
+        .flatten()
+        .all(|&c| -ml_dsa_44::GAMMA1 < c && c <= ml_dsa_44::GAMMA1));
+}
+
+#[test]
+fn ml_dsa_65_expand_mask() {
+    let y = ml_dsa_65::expand_mask(&RHO_PRIME, 7);
+
+    assert_eq!(
+        [109661, 426277, -205830, -461516, 239404, 326097],
+        y[4][..6]
+    );
+    assert_eq!(-204623, y[4][255]);
+    assert_eq!(-3252746, sum(&y[4]));
+}
+
+#[test]
+fn ml_dsa_44_sample_in_ball() {
+    const C: [i32; 256] = ml_dsa_44::sample_in_ball(&RHO);
+    let nonzero: Vec<_> = (0..256).filter(|&i| C[i] != 0).take(8).collect();
+
+    assert_eq!(vec![3, 7, 9, 19, 30, 44, 57, 61], nonzero);
+    assert_eq!(
+        vec![-1, 1, 1, -1, -1, 1, -1, -1],
+        nonzero.iter().map(|&i| C[i]).collect::<Vec<_>>()
+    );
+    assert_eq!(7, sum(&C));
+    assert_eq!(ml_dsa_44::TAU, C.iter().filter(|&&c| c != 0).count());
+}
+
+#[test]
+fn ml_dsa_87_sample_in_ball() {
+    let c = ml_dsa_87::sample_in_ball(&RHO_PRIME);
+    let nonzero: Vec<_> = (0..256).filter(|&i| c[i] != 0).take(8).collect();
+
+    assert_eq!(vec![1, 8, 13, 15, 18, 25, 32, 36], nonzero);
+    assert_eq!(
+        vec![1, 1, -1, -1, -1, 1, 1, 1],
+        nonzero.iter().map(|&i| c[i]).collect::<Vec<_>>()
+    );
+    assert_eq!(0, sum(&c));
+    assert_eq!(ml_dsa_87::TAU, c.iter().filter(|&&c| c != 0).count());
+}
+
+/// `ζ^BitRev8(k) mod q` with `ζ = 1753`
+fn zeta(k: usize) -> i64 {
+    let e = (k as u8).reverse_bits();
+    (0..e).fold(1, |z, _| z * 1753 % i64::from(ml_dsa::Q))
+}
+
+/// `NTT(w)`
+fn ntt(w: &[i32; 256]) -> [i64; 256] {
+    let q = i64::from(ml_dsa::Q);
+    let mut w = w.map(|c| i64::from(c).rem_euclid(q));
+    let mut m = 0;
+    let mut len = 128;
+    while len >= 1 {
+        for start in (0..256).step_by(2 * len) {
+            m += 1;
+            let z = zeta(m);
+            for j in start..start + len {
+                let t = z * w[j + len] % q;
+                w[j + len] = (w[j] - t).rem_euclid(q);
+                w[j] = (w[j] + t) % q;
+            }
+        }
+        len /= 2;
+    }
+    w
+}
+
+/// `NTT^-1(w)`
+fn inverse_ntt(mut w: [i64; 256]) -> [i64; 256] {
+    let q = i64::from(ml_dsa::Q);
+    let mut m = 256;
+    let mut len = 1;
+    while len < 256 {
+        for start in (0..256).step_by(2 * len) {
+            m -= 1;
+            let z = q - zeta(m);
+            for j in start..start + len {
+                let t = w[j];
+                w[j] = (t + w[j + len]) % q;
+                w[j + len] = (t - w[j + len]).rem_euclid(q) * z % q;
+            }
+        }
+        len *= 2;
+    }
+    w.map(|c| c * 8347681 % q)
+}
+
+/// `ρ` and `ρ'` of `ML-DSA.KeyGen_internal(ξ)`
+fn expand_seed(xi: &[u8; 32], k: usize, l: usize) -> ([u8; 32], [u8; 64]) {
+    let h: [u8; 128] = Shake256::new()
+        .update(xi)
+        .update(&[k as u8, l as u8])
+        .finalize();
+    (h[..32].try_into().unwrap(), h[32..96].try_into().unwrap())
+}
+
+/// `pkEncode(ρ, t1)` with `t = NTT^-1(Â ∘ NTT(s1)) + s2`
+fn public_key<const K: usize, const L: usize>(
+    rho: &[u8; 32],
+    a_hat: &[[[i32; 256]; L]; K],
+    s1: &[[i32; 256]; L],
+    s2: &[[i32; 256]; K],
+) -> Vec<u8> {
+    let q = i64::from(ml_dsa::Q);
+    let s1_hat = s1.map(|s| ntt(&s));
+    let mut pk = rho.to_vec();
+    for (a_hat, s2) in a_hat.iter().zip(s2) {
+        let mut t_hat = [0; 256];
+        for (a, s) in a_hat.iter().zip(&s1_hat) {
+            for c in 0..256 {
+                t_hat[c] = (t_hat[c] + i64::from(a[c]) * s[c]) % q;
+            }
+        }
+        let mut packed = 0u128;
+        let mut bits = 0;
+        for (t, s) in inverse_ntt(t_hat).iter().zip(s2) {
+            let t = (t + i64::from(*s)).rem_euclid(q);
+            let t0 = (t + (1 << 12) - 1) % (1 << 13) - ((1 << 12) - 1);
+            packed |= (((t - t0) >> 13) as u128) << bits;
+            bits += 10;
+            while bits >= 8 {
+                pk.push(packed as u8);
+                packed >>= 8;
+                bits -= 8;
+            }
+        }
+    }
+    pk
+}
+
+#[test]
+fn key_generation() {
+    // ξ is RHO
+    let (rho, rho_prime) = expand_seed(&RHO, ml_dsa_44::K, ml_dsa_44::L);
+    let (s1, s2) = ml_dsa_44::expand_s(&rho_prime);
+    let pk = public_key(&rho, &ml_dsa_44::expand_a(&rho), &s1, &s2);
+    assert_eq!(
+        common::hex::<32>("329a07b1fabb48f52a309f11a1898f848e2322ffe623ec810db3bee33685854a"),
+        Shake256::new().update(&pk).finalize(),
+    );
+
+    let (rho, rho_prime) = expand_seed(&RHO, ml_dsa_65::K, ml_dsa_65::L);
+    let (s1, s2) = ml_dsa_65::expand_s(&rho_prime);
+    let pk = public_key(&rho, &ml_dsa_65::expand_a(&rho), &s1, &s2);
+    assert_eq!(
+        common::hex::<32>("1b0563e3cd3346149c8c9ebcf23b0a4e5a900eea9c6562790a7c63e38663daa2"),
+        Shake256::new().update(&pk).finalize(),
+    );
+
+    let (rho, rho_prime) = expand_seed(&RHO, ml_dsa_87::K, ml_dsa_87::L);
+    let (s1, s2) = ml_dsa_87::expand_s(&rho_prime);
+    let pk = public_key(&rho, &ml_dsa_87::expand_a(&rho), &s1, &s2);
+    assert_eq!(
+        common::hex::<32>("89886750b57c24db3fc012e61ede59753337374fa7124991549af243496d0637"),
+        Shake256::new().update(&pk).finalize(),
+    );
+}
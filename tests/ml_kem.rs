@@ -0,0 +1,202 @@
+//! Expected values were computed with a separate Python implementation of
+//! FIPS 203 on top of `hashlib`. Its key generation gives the encapsulation
+//! keys of OpenSSL 3.5 for the seed of `key_generation`, whose expected hashes
+//! are those of the OpenSSL keys.
+
+mod common;
+
+use keccak_const::ml_kem;
+
+const RHO: [u8; 32] = [
+    0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0a, 0x0b, 0x0c, 0x0d, 0x0e, 0x0f,
+    0x10, 0x11, 0x12, 0x13, 0x14, 0x15, 0x16, 0x17, 0x18, 0x19, 0x1a, 0x1b, 0x1c, 0x1d, 0x1e, 0x1f,
+];
+
+#[test]
+fn h_abc() {
+    assert_eq!(
+        [
+            0x3a, 0x98, 0x5d, 0xa7, 0x4f, 0xe2, 0x25, 0xb2, 0x04, 0x5c, 0x17, 0x2d, 0x6b, 0xd3,
+            0x90, 0xbd, 0x85, 0x5f, 0x08, 0x6e, 0x3e, 0x9d, 0x52, 0x5b, 0x46, 0xbf, 0xe2, 0x45,
+            0x11, 0x43, 0x15, 0x32,
+        ],
+        ml_kem::h(b"abc"),
+    );
+}
+
+#[test]
+fn j_abc() {
+    assert_eq!(
+        [
+            0x48, 0x33, 0x66, 0x60, 0x13, 0x60, 0xa8, 0x77, 0x1c, 0x68, 0x63, 0x08, 0x0c, 0xc4,
+            0x11, 0x4d, 0x8d, 0xb4, 0x45, 0x30, 0xf8, 0xf1, 0xe1, 0xee, 0x4f, 0x94, 0xea, 0x37,
+            0xe7, 0x8b, 0x57, 0x39,
+        ],
+        ml_kem::j(b"abc"),
+    );
+}
+
+#[test]
+fn g_abc() {
+    let (a, b) = ml_kem::g(b"abc");
+
+    assert_eq!(
+        [
+            0xb7, 0x51, 0x85, 0x0b, 0x1a, 0x57, 0x16, 0x8a, 0x56, 0x93, 0xcd, 0x92, 0x4b, 0x6b,
+            0x09, 0x6e, 0x08, 0xf6, 0x21, 0x82, 0x74, 0x44, 0xf7, 0x0d, 0x88, 0x4f, 0x5d, 0x02,
+            0x40, 0xd2, 0x71, 0x2e,
+        ],
+        a,
+    );
+    assert_eq!(
+        [
+            0x10, 0xe1, 0x16, 0xe9, 0x19, 0x2a, 0xf3, 0xc9, 0x1a, 0x7e, 0xc5, 0x76, 0x47, 0xe3,
+            0x93, 0x40, 0x57, 0x34, 0x0b, 0x4c, 0xf4, 0x08, 0xd5, 0xa5, 0x65, 0x92, 0xf8, 0x27,
+            0x4e, 0xec, 0x53, 0xf0,
+        ],
+        b,
+    );
+}
+
+#[test]
+fn prf_eta_2() {
+    let output: [u8; 128] = ml_kem::prf(&RHO, 5);
+
+    assert_eq!(
+        [
+            0x79, 0x7f, 0x16, 0x48, 0xf9, 0x92, 0xcc, 0x90, 0x03, 0x0e, 0xdf, 0x6f, 0xdc, 0x1d,
+            0xbf, 0x49,
+        ],
+        output[..16],
+    );
+}
+
+#[test]
+#[should_panic(expected = "invalid PRF output length")]
+fn prf_invalid_eta() {
+    ml_kem::prf::<64>(&RHO, 0);
+}
+
+#[test]
+fn sample_ntt_0_0() {
+    const A: [u16; 256] = ml_kem::sample_ntt(&RHO, 0, 0);
+
+    assert_eq!([481, 1919, 1434, 2359, 327, 1066, 3001, 649], A[..8]);
+    assert_eq!(3216, A[255]);
+    assert_eq!(428176, A.iter().map(|&c| u32::from(c)).sum::<u32>());
+}
+
+#[test]
+fn sample_ntt_1_2() {
+    let a = ml_kem::sample_ntt(&RHO, 1, 2);
+
+    assert_eq!([2160, 550, 1910, 2647, 219, 271, 2843, 2289], a[..8]);
+    assert_eq!(288, a[255]);
+    assert_eq!(425825, a.iter().map(|&c| u32::from(c)).sum::<u32>());
+}
+
+#[test]
+fn expand_matrix() {
+    let a = ml_kem::expand_matrix::<3>(&RHO);
+
+    for i in 0..3 {
+        for j in 0..3 {
+            assert_eq!(ml_kem::sample_ntt(&RHO, i, j), a[i as usize][j as usize]);
+            assert!(a[i as usize][j as usize].iter().all(|&c| c < ml_kem::Q));
+        }
+    }
+}
+
+/// `17^e mod q`
+fn pow17(e: usize) -> u32 {
+    (0..e).fold(1, |z, _| z * 17 % u32::from(ml_kem::Q))
+}
+
+/// `BitRev7(i)`
+fn bit_rev7(i: usize) -> usize {
+    usize::from((i as u8).reverse_bits() >> 1)
+}
+
+/// `NTT(f)`
+fn ntt(mut f: [u32; 256]) -> [u32; 256] {
+    let q = u32::from(ml_kem::Q);
+    let mut i = 1;
+    let mut len = 128;
+    while len >= 2 {
+        for start in (0..256).step_by(2 * len) {
+            let zeta = pow17(bit_rev7(i));
+            i += 1;
+            for j in start..start + len {
+                let t = zeta * f[j + len] % q;
+                f[j + len] = (f[j] + q - t) % q;
+                f[j] = (f[j] + t) % q;
+            }
+        }
+        len /= 2;
+    }
+    f
+}
+
+/// `SamplePolyCBD_η(bytes)`
+fn sample_poly_cbd(bytes: &[u8], eta: usize) -> [u32; 256] {
+    let q = u32::from(ml_kem::Q);
+    let bit = |k: usize| u32::from(bytes[k / 8] >> (k % 8) & 1);
+    core::array::from_fn(|i| {
+        let x: u32 = (0..eta).map(|j| bit(2 * i * eta + j)).sum();
+        let y: u32 = (0..eta).map(|j| bit(2 * i * eta + eta + j)).sum();
+        (x + q - y) % q
+    })
+}
+
+/// The encapsulation key of `K-PKE.KeyGen(d)`, with `η1 = PRF_LEN / 64`
+fn encapsulation_key<const K: usize, const PRF_LEN: usize>(d: &[u8; 32]) -> Vec<u8> {
+    let q = u32::from(ml_kem::Q);
+    let (rho, sigma) = ml_kem::g(&[&d[..], &[K as u8]].concat());
+    let a = ml_kem::expand_matrix::<K>(&rho);
+    let sample = |n: usize| {
+        ntt(sample_poly_cbd(
+            &ml_kem::prf::<PRF_LEN>(&sigma, n as u8),
+            PRF_LEN / 64,
+        ))
+    };
+    let s: Vec<_> = (0..K).map(sample).collect();
+
+    let mut ek = Vec::new();
+    for i in 0..K {
+        let mut t = sample(K + i);
+        for (a, s) in a[i].iter().zip(&s) {
+            for c in 0..128 {
+                let gamma = pow17(2 * bit_rev7(c) + 1);
+                let (a0, a1) = (u32::from(a[2 * c]), u32::from(a[2 * c + 1]));
+                t[2 * c] = (t[2 * c] + a0 * s[2 * c] + a1 * s[2 * c + 1] % q * gamma) % q;
+                t[2 * c + 1] = (t[2 * c + 1] + a0 * s[2 * c + 1] + a1 * s[2 * c]) % q;
+            }
+        }
+        for pair in t.chunks(2) {
+            ek.extend([
+                pair[0] as u8,
+                (pair[0] >> 8 | pair[1] << 4) as u8,
+                (pair[1] >> 4) as u8,
+            ]);
+        }
+    }
+    ek.extend(rho);
+    ek
+}
+
+#[test]
+fn key_generation() {
+    // d is RHO, the seed is d || z with z = 0x20..0x3f
+    assert_eq!(
+        common::hex::<32>("82f101ff648063b376e2bb6c5b7455f655a50c2feadade150efa0e0e6f365aea"),
+        ml_kem::h(&encapsulation_key::<2, 192>(&RHO)),
+    );
+    assert_eq!(
+        common::hex::<32>("a24e16d8f8f9383a95b77050f4d9fd2f5733eec1d63ef3c23ebf9918173669a7"),
+        ml_kem::h(&encapsulation_key::<3, 128>(&RHO)),
+    );
+    assert_eq!(
+        common::hex::<32>("61349e5c131a7e116a0463861d7d18663c5627c38c7147ddaadfd48acd7a4535"),
+        ml_kem::h(&encapsulation_key::<4, 128>(&RHO)),
+    );
+}
@@ -0,0 +1,107 @@
+use keccak_const::selector_table::SelectorTable;
+use keccak_const::solidity::function_selector;
+
+const ERC20: [&str; 6] = [
+    "totalSupply()",
+    "balanceOf(address)",
+    "transfer(address,uint256)",
+    "allowance(address,address)",
+    "approve(address,uint256)",
+    "transferFrom(address,address,uint256)",
+];
+
+#[test]
+fn erc20() {
+    const TABLE: SelectorTable<6> = SelectorTable::new(&ERC20);
+
+    assert_eq!(
+        [
+            [0x09, 0x5e, 0xa7, 0xb3],
+            [0x18, 0x16, 0x0d, 0xdd],
+            [0x23, 0xb8, 0x72, 0xdd],
+            [0x70, 0xa0, 0x82, 0x31],
+            [0xa9, 0x05, 0x9c, 0xbb],
+            [0xdd, 0x62, 0xed, 0x3e],
+        ],
+        TABLE.selectors()
+    );
+    assert_eq!(&[4, 0, 5, 1, 2, 3], TABLE.indices());
+    for (i, signature) in ERC20.iter().enumerate() {
+        let selector = function_selector(signature);
+        assert_eq!(Some(i), TABLE.lookup_selector(selector));
+
+        let mut calldata = selector.to_vec();
+        assert_eq!(Some(i), TABLE.lookup(&calldata));
+        calldata.extend([0; 64]);
+        assert_eq!(Some(i), TABLE.lookup(&calldata));
+    }
+}
+
+#[test]
+fn missing() {
+    const TABLE: SelectorTable<6> = SelectorTable::new(&ERC20);
+
+    assert_eq!(None, TABLE.lookup(&[]));
+    assert_eq!(None, TABLE.lookup(&[0xa9, 0x05, 0x9c]));
+    assert_eq!(None, TABLE.lookup(&[0xa9, 0x05, 0x9c, 0xbc]));
+    assert_eq!(None, TABLE.lookup(&[0x00, 0x00, 0x00, 0x00]));
+    assert_eq!(None, TABLE.lookup(&[0xff, 0xff, 0xff, 0xff]));
+    assert_eq!(
+        None,
+        TABLE.lookup_selector(function_selector("mint(address,uint256)"))
+    );
+}
+
+#[test]
+fn empty() {
+    const TABLE: SelectorTable<0> = SelectorTable::new(&[]);
+
+    assert_eq!(None, TABLE.lookup(&[0xa9, 0x05, 0x9c, 0xbb]));
+}
+
+#[test]
+fn lookup_is_const() {
+    const TABLE: SelectorTable<6> = SelectorTable::new(&ERC20);
+    const TRANSFER: Option<usize> = TABLE.lookup(&[0xa9, 0x05, 0x9c, 0xbb, 0x00]);
+
+    assert_eq!(Some(2), TRANSFER);
+}
+
+#[test]
+fn many_signatures() {
+    let names: Vec<String> = (0..64).map(|i| format!("f{i}(uint256)")).collect();
+    let signatures: [&str; 64] = std::array::from_fn(|i| names[i].as_str());
+    let table = SelectorTable::new(&signatures);
+
+    let selectors = table.selectors();
+    assert!(selectors.windows(2).all(|w| w[0] < w[1]));
+    for (i, signature) in signatures.iter().enumerate() {
+        assert_eq!(Some(i), table.lookup_selector(function_selector(signature)));
+    }
+}
+
+#[test]
+#[should_panic(
+    expected = "selector collision between `burn(uint256)` and `collate_propagate_storage(bytes16)`"
+)]
+fn collision() {
+    SelectorTable::new(&[
+        "burn(uint256)",
+        "transfer(address,uint256)",
+        "collate_propagate_storage(bytes16)",
+    ]);
+}
+
+#[test]
+#[should_panic(
+    expected = "selector collision between `approve(address,uint256)` and `approve(address,uint256)`"
+)]
+fn duplicate() {
+    SelectorTable::new(&ERC20.map(|_| "approve(address,uint256)"));
+}
+
+#[test]
+#[should_panic(expected = "`uint` must be written as `uint256`")]
+fn non_canonical() {
+    SelectorTable::new(&["transfer(address,uint)"]);
+}
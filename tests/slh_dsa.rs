@@ -0,0 +1,254 @@
+//! Expected values were computed with a separate Python implementation of
+//! FIPS 205 on top of `hashlib`, except those of `key_generation` and
+//! `deterministic_signature`, which come from SLH-DSA-SHAKE-128f keys and
+//! signatures of OpenSSL 3.5.
+
+mod common;
+
+use keccak_const::slh_dsa;
+use keccak_const::slh_dsa::Adrs;
+use keccak_const::slh_dsa::AdrsType;
+
+const PK_SEED: [u8; 16] = [
+    0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0a, 0x0b, 0x0c, 0x0d, 0x0e, 0x0f,
+];
+
+const SK_SEED: [u8; 16] = [
+    0x10, 0x11, 0x12, 0x13, 0x14, 0x15, 0x16, 0x17, 0x18, 0x19, 0x1a, 0x1b, 0x1c, 0x1d, 0x1e, 0x1f,
+];
+
+const SK_PRF: [u8; 16] = [
+    0x20, 0x21, 0x22, 0x23, 0x24, 0x25, 0x26, 0x27, 0x28, 0x29, 0x2a, 0x2b, 0x2c, 0x2d, 0x2e, 0x2f,
+];
+
+const fn sequence<const N: usize>() -> [u8; N] {
+    let mut output = [0; N];
+    let mut i = 0;
+    while i < N {
+        output[i] = i as u8;
+        i += 1;
+    }
+    output
+}
+
+#[test]
+fn adrs_layout() {
+    let adrs = Adrs::new()
+        .set_layer_address(3)
+        .set_tree_address(0x0102030405060708)
+        .set_type_and_clear(AdrsType::WotsHash)
+        .set_key_pair_address(7)
+        .set_chain_address(5)
+        .set_hash_address(2);
+
+    assert_eq!(
+        [
+            0x00, 0x00, 0x00, 0x03, 0x00, 0x00, 0x00, 0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06,
+            0x07, 0x08, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x07, 0x00, 0x00, 0x00, 0x05,
+            0x00, 0x00, 0x00, 0x02,
+        ],
+        adrs.to_bytes(),
+    );
+    assert_eq!(7, adrs.key_pair_address());
+    assert_eq!(2, adrs.tree_index());
+}
+
+#[test]
+fn adrs_set_type_and_clear() {
+    let adrs = Adrs::new()
+        .set_layer_address(1)
+        .set_tree_address(2)
+        .set_key_pair_address(3)
+        .set_tree_height(4)
+        .set_tree_index(5)
+        .set_type_and_clear(AdrsType::ForsRoots);
+
+    assert_eq!(
+        Adrs::new()
+            .set_layer_address(1)
+            .set_tree_address(2)
+            .set_type_and_clear(AdrsType::ForsRoots),
+        adrs,
+    );
+    assert_eq!(4, adrs.to_bytes()[19]);
+    assert_eq!(0, adrs.key_pair_address());
+}
+
+#[test]
+fn f_n16() {
+    const ADRS: Adrs = Adrs::new()
+        .set_layer_address(3)
+        .set_tree_address(0x0102030405060708)
+        .set_key_pair_address(7)
+        .set_chain_address(5)
+        .set_hash_address(2);
+    const OUTPUT: [u8; 16] = slh_dsa::f(&PK_SEED, &ADRS, &SK_SEED);
+
+    assert_eq!(
+        [
+            0x2b, 0x45, 0x70, 0x6a, 0x3d, 0x0b, 0x66, 0x77, 0x45, 0x69, 0xfc, 0x0d, 0xf7, 0xa0,
+            0x89, 0xaa,
+        ],
+        OUTPUT,
+    );
+}
+
+#[test]
+fn h_n24() {
+    let pk_seed: [u8; 24] = sequence();
+    let m2: [u8; 48] = sequence();
+    let adrs = Adrs::new()
+        .set_tree_address(9)
+        .set_type_and_clear(AdrsType::Tree)
+        .set_tree_height(4)
+        .set_tree_index(11);
+    let mut left = [0; 24];
+    let mut right = [0; 24];
+    left.copy_from_slice(&m2[..24]);
+    right.copy_from_slice(&m2[24..]);
+
+    assert_eq!(
+        [
+            0xec, 0xb0, 0x54, 0x34, 0xfe, 0xf1, 0x02, 0x4e, 0x5c, 0xd2, 0x28, 0xe7, 0xd1, 0x4d,
+            0xda, 0xbf, 0x5f, 0x43, 0x4a, 0xf5, 0xcf, 0xe9, 0xc7, 0xc5,
+        ],
+        slh_dsa::h(&pk_seed, &adrs, &left, &right),
+    );
+}
+
+#[test]
+fn t_l_n16() {
+    let m: [u8; 80] = sequence();
+    let blocks: Vec<[u8; 16]> = m.chunks(16).map(|b| b.try_into().unwrap()).collect();
+    let adrs = Adrs::new()
+        .set_layer_address(1)
+        .set_tree_address(2)
+        .set_type_and_clear(AdrsType::WotsPk)
+        .set_key_pair_address(3);
+
+    assert_eq!(
+        [
+            0x79, 0xe6, 0xfe, 0xaa, 0x43, 0x71, 0x7b, 0xcd, 0x2c, 0xeb, 0x7f, 0xcf, 0x6e, 0xe2,
+            0xdd, 0x09,
+        ],
+        slh_dsa::t_l(&PK_SEED, &adrs, &blocks),
+    );
+}
+
+#[test]
+fn prf_n16() {
+    let adrs = Adrs::new()
+        .set_type_and_clear(AdrsType::WotsPrf)
+        .set_key_pair_address(1)
+        .set_chain_address(2);
+
+    assert_eq!(
+        [
+            0xe0, 0x7d, 0x84, 0xca, 0x10, 0x93, 0xab, 0x0f, 0xd6, 0xa7, 0xb1, 0x17, 0xe5, 0x92,
+            0xb4, 0xfd,
+        ],
+        slh_dsa::prf(&PK_SEED, &SK_SEED, &adrs),
+    );
+}
+
+#[test]
+fn prf_msg_n16() {
+    assert_eq!(
+        [
+            0x1b, 0x89, 0xda, 0x44, 0x1d, 0x30, 0xb4, 0xc8, 0x19, 0x6e, 0x20, 0xf1, 0xbd, 0x2b,
+            0xa4, 0x49,
+        ],
+        slh_dsa::prf_msg(&SK_SEED, &PK_SEED, b"message"),
+    );
+}
+
+#[test]
+fn h_msg_n16_m30() {
+    let output: [u8; 30] = slh_dsa::h_msg(&SK_SEED, &PK_SEED, &[0; 16], b"message");
+
+    assert_eq!(
+        [
+            0x5f, 0x10, 0x60, 0x07, 0x88, 0x9a, 0x2d, 0xf9, 0xfb, 0x60, 0x3b, 0xd8, 0xd3, 0x66,
+            0x9a, 0x55, 0xc4, 0x22, 0x52, 0xcc, 0xbc, 0x3f, 0x04, 0x39, 0x89, 0xc2, 0x29, 0xad,
+            0x35, 0xab,
+        ],
+        output,
+    );
+}
+
+/// `wots_pkGen` of SLH-DSA-SHAKE-128f, with `len = 35` chains of `w = 16`
+fn wots_pk_gen(adrs: Adrs) -> [u8; 16] {
+    let i = adrs.key_pair_address();
+    let chains: Vec<[u8; 16]> = (0..35)
+        .map(|c| {
+            let sk_adrs = adrs
+                .set_type_and_clear(AdrsType::WotsPrf)
+                .set_key_pair_address(i)
+                .set_chain_address(c);
+            (0..15).fold(slh_dsa::prf(&PK_SEED, &SK_SEED, &sk_adrs), |tmp, j| {
+                slh_dsa::f(
+                    &PK_SEED,
+                    &adrs.set_chain_address(c).set_hash_address(j),
+                    &tmp,
+                )
+            })
+        })
+        .collect();
+    let pk_adrs = adrs
+        .set_type_and_clear(AdrsType::WotsPk)
+        .set_key_pair_address(i);
+    slh_dsa::t_l(&PK_SEED, &pk_adrs, &chains)
+}
+
+/// `xmss_node(SK.seed, i, z, PK.seed, ADRS)`
+fn xmss_node(adrs: Adrs, i: u32, z: u32) -> [u8; 16] {
+    if z == 0 {
+        return wots_pk_gen(
+            adrs.set_type_and_clear(AdrsType::WotsHash)
+                .set_key_pair_address(i),
+        );
+    }
+    let left = xmss_node(adrs, 2 * i, z - 1);
+    let right = xmss_node(adrs, 2 * i + 1, z - 1);
+    let adrs = adrs
+        .set_type_and_clear(AdrsType::Tree)
+        .set_tree_height(z)
+        .set_tree_index(i);
+    slh_dsa::h(&PK_SEED, &adrs, &left, &right)
+}
+
+/// `PK.root` of the OpenSSL key with the seed `SK.seed || SK.prf || PK.seed`
+const PK_ROOT: &str = "505dfd70cb51b093d43f44479418fddb";
+
+#[test]
+fn key_generation() {
+    // the top XMSS tree, in layer d - 1 = 21, has height h' = 3
+    let adrs = Adrs::new().set_layer_address(21);
+
+    assert_eq!(common::hex::<16>(PK_ROOT), xmss_node(adrs, 0, 3));
+}
+
+#[test]
+fn deterministic_signature() {
+    // the signature of `abc` with an empty context starts with R and the first
+    // FORS secret key
+    let signature =
+        common::hex::<32>("10897d05e01bcdbd1fd2abe95e524cf3cb83f1581c85113ba5481bcd08102fe6");
+    // M' = 0 || |ctx| || ctx || M of the pure signature
+    let message = b"\x00\x00abc";
+
+    let r = slh_dsa::prf_msg(&SK_PRF, &PK_SEED, message);
+    assert_eq!(signature[..16], r);
+
+    // md has k * a = 33 * 6 bits, followed by the tree and leaf indices of
+    // h - h' = 63 and h' = 3 bits
+    let digest: [u8; 34] = slh_dsa::h_msg(&r, &PK_SEED, &common::hex(PK_ROOT), message);
+    let idx_tree = u64::from_be_bytes(digest[25..33].try_into().unwrap()) & (u64::MAX >> 1);
+    let idx_leaf = u32::from(digest[33] & 0x07);
+    let sk_adrs = Adrs::new()
+        .set_tree_address(idx_tree)
+        .set_type_and_clear(AdrsType::ForsPrf)
+        .set_key_pair_address(idx_leaf)
+        .set_tree_index(u32::from(digest[0] >> 2));
+    assert_eq!(signature[16..], slh_dsa::prf(&PK_SEED, &SK_SEED, &sk_adrs));
+}
@@ -0,0 +1,175 @@
+use keccak_const::address::Address;
+use keccak_const::sol_type::event_topic_of;
+use keccak_const::sol_type::selector_of;
+use keccak_const::sol_type::AbiName;
+use keccak_const::sol_type::Bytes;
+use keccak_const::sol_type::FixedBytes;
+use keccak_const::sol_type::SolType;
+use keccak_const::sol_type::I256;
+use keccak_const::sol_type::U256;
+use keccak_const::solidity::event_topic;
+use keccak_const::solidity::function_selector;
+
+#[test]
+fn abi_name() {
+    const NAME: AbiName = AbiName::new()
+        .push_bytes(b"uint")
+        .push_decimal(256)
+        .push_bytes(b"[")
+        .push_decimal(0)
+        .push_bytes(b"]");
+
+    assert_eq!("uint256[0]", NAME.as_str());
+    assert_eq!(b"uint256[0]", NAME.as_bytes());
+    assert_eq!("", AbiName::new().as_str());
+    assert_eq!(
+        "address[18446744073709551615]",
+        <Address as SolType>::ABI_NAME
+            .push_bytes(b"[")
+            .push_decimal(usize::MAX)
+            .push_bytes(b"]")
+            .as_str()
+    );
+}
+
+#[test]
+#[should_panic(expected = "ABI type name too long")]
+fn abi_name_too_long() {
+    AbiName::new().push_bytes(&[b'a'; 257]);
+}
+
+#[test]
+fn primitives() {
+    for (name, expected) in [
+        (bool::ABI_NAME, "bool"),
+        (u8::ABI_NAME, "uint8"),
+        (u16::ABI_NAME, "uint16"),
+        (u32::ABI_NAME, "uint32"),
+        (u64::ABI_NAME, "uint64"),
+        (u128::ABI_NAME, "uint128"),
+        (i8::ABI_NAME, "int8"),
+        (i16::ABI_NAME, "int16"),
+        (i32::ABI_NAME, "int32"),
+        (i64::ABI_NAME, "int64"),
+        (i128::ABI_NAME, "int128"),
+        (U256::ABI_NAME, "uint256"),
+        (I256::ABI_NAME, "int256"),
+        (Address::ABI_NAME, "address"),
+        (FixedBytes::<1>::ABI_NAME, "bytes1"),
+        (FixedBytes::<32>::ABI_NAME, "bytes32"),
+        (Bytes::ABI_NAME, "bytes"),
+        (str::ABI_NAME, "string"),
+        (<&str>::ABI_NAME, "string"),
+    ] {
+        assert_eq!(expected, name.as_str());
+    }
+}
+
+#[test]
+fn arrays() {
+    assert_eq!("uint8[20]", <[u8; 20]>::ABI_NAME.as_str());
+    assert_eq!("address[]", <[Address]>::ABI_NAME.as_str());
+    assert_eq!("address[]", <&[Address]>::ABI_NAME.as_str());
+    assert_eq!("string[][3]", <[&[&str]; 3]>::ABI_NAME.as_str());
+    assert_eq!("bytes32[2][]", <&[[FixedBytes<32>; 2]]>::ABI_NAME.as_str());
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn vec() {
+    assert_eq!("uint256[]", <Vec<U256>>::ABI_NAME.as_str());
+    assert_eq!("string[][]", <Vec<Vec<String>>>::ABI_NAME.as_str());
+    assert_eq!("(bool,bytes)[]", <Vec<(bool, Bytes)>>::ABI_NAME.as_str());
+}
+
+#[test]
+fn tuples() {
+    assert_eq!("()", <()>::ABI_NAME.as_str());
+    assert_eq!("(bool)", <(bool,)>::ABI_NAME.as_str());
+    assert_eq!(
+        "(address,uint256)[2]",
+        <[(Address, U256); 2]>::ABI_NAME.as_str()
+    );
+    assert_eq!(
+        "(((uint8,int128),address[])[2][],uint64)",
+        <(&[[((u8, i128), &[Address]); 2]], u64)>::ABI_NAME.as_str()
+    );
+    assert_eq!(
+        "(uint8,uint16,uint32,uint64,uint128,uint256,int8,int16,int32,int64,int128,int256)",
+        <(u8, u16, u32, u64, u128, U256, i8, i16, i32, i64, i128, I256)>::ABI_NAME.as_str()
+    );
+}
+
+#[test]
+fn selectors() {
+    const TRANSFER: [u8; 4] = selector_of::<(Address, U256)>("transfer");
+    const TRANSFER_EVENT: [u8; 32] = event_topic_of::<(Address, Address, U256)>("Transfer");
+
+    assert_eq!([0xa9, 0x05, 0x9c, 0xbb], TRANSFER);
+    assert_eq!(
+        [
+            0xdd, 0xf2, 0x52, 0xad, 0x1b, 0xe2, 0xc8, 0x9b, 0x69, 0xc2, 0xb0, 0x68, 0xfc, 0x37,
+            0x8d, 0xaa, 0x95, 0x2b, 0xa7, 0xf1, 0x63, 0xc4, 0xa1, 0x16, 0x28, 0xf5, 0x5a, 0x4d,
+            0xf5, 0x23, 0xb3, 0xef,
+        ],
+        TRANSFER_EVENT
+    );
+    assert_eq!([0xb8, 0xc9, 0xd3, 0x65], selector_of::<()>("h"));
+}
+
+#[test]
+fn nested_selectors() {
+    assert_eq!(
+        [0xee, 0xe0, 0xf4, 0xd4],
+        selector_of::<(&[(Address, U256)], [(bool, FixedBytes<32>); 3], &str, Bytes)>("f")
+    );
+    assert_eq!(
+        function_selector("f((address,uint256)[],(bool,bytes32)[3],string,bytes)"),
+        selector_of::<(&[(Address, U256)], [(bool, FixedBytes<32>); 3], &str, Bytes)>("f")
+    );
+    assert_eq!(
+        [0x0c, 0x46, 0x3e, 0x7e],
+        selector_of::<(&[[((u8, i128), &[Address]); 2]], u64)>("g")
+    );
+    assert_eq!(
+        event_topic("g(((uint8,int128),address[])[2][],uint64)"),
+        event_topic_of::<(&[[((u8, i128), &[Address]); 2]], u64)>("g")
+    );
+}
+
+#[test]
+#[should_panic(expected = "parameters must be a tuple")]
+fn selector_of_non_tuple() {
+    selector_of::<U256>("f");
+}
+
+#[test]
+fn u256_arithmetic() {
+    let mut low = [0; 32];
+    low[31] = 0xff;
+    let mut carried = [0; 32];
+    carried[30] = 0x01;
+    let mut high = [0xff; 32];
+    high[0] = 0x7f;
+    let mut top_bit = [0; 32];
+    top_bit[0] = 0x80;
+    let mut max_u128 = [0; 32];
+    max_u128[16..].copy_from_slice(&[0xff; 16]);
+
+    assert_eq!(U256(max_u128), U256::from_u128(u128::MAX));
+    assert_eq!(U256(carried), U256(low).wrapping_add(&U256::from_u128(1)));
+    assert_eq!(U256([0xff; 32]), U256(high).wrapping_add(&U256(top_bit)));
+    assert_eq!(
+        U256([0; 32]),
+        U256([0xff; 32]).wrapping_add(&U256::from_u128(1))
+    );
+
+    assert_eq!(U256(low), U256(carried).wrapping_sub(&U256::from_u128(1)));
+    assert_eq!(U256(high), U256([0xff; 32]).wrapping_sub(&U256(top_bit)));
+    assert_eq!(
+        U256([0xff; 32]),
+        U256::default().wrapping_sub(&U256::from_u128(1))
+    );
+    assert_eq!(U256(top_bit), U256([0xff; 32]).bitand(&U256(top_bit)));
+    assert_eq!(U256::default(), U256(high).bitand(&U256(top_bit)));
+}
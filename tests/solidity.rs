@@ -0,0 +1,444 @@
+use keccak_const::address::Address;
+use keccak_const::sol_type::I256;
+use keccak_const::sol_type::U256;
+use keccak_const::solidity::erc1820_interface_hash;
+use keccak_const::solidity::error_selector;
+use keccak_const::solidity::event_topic;
+use keccak_const::solidity::function_selector;
+use keccak_const::solidity::function_selector_u32;
+use keccak_const::solidity::indexed_address;
+use keccak_const::solidity::indexed_array;
+use keccak_const::solidity::indexed_bool;
+use keccak_const::solidity::indexed_bytes;
+use keccak_const::solidity::indexed_bytes_n;
+use keccak_const::solidity::indexed_i256;
+use keccak_const::solidity::indexed_int;
+use keccak_const::solidity::indexed_string;
+use keccak_const::solidity::indexed_u256;
+use keccak_const::solidity::indexed_uint;
+use keccak_const::solidity::interface_id;
+use keccak_const::solidity::Signature;
+use keccak_const::Keccak256;
+
+#[test]
+fn erc20_selectors() {
+    const TOTAL_SUPPLY: [u8; 4] = function_selector("totalSupply()");
+
+    assert_eq!([0x18, 0x16, 0x0d, 0xdd], TOTAL_SUPPLY);
+    assert_eq!(
+        [0x70, 0xa0, 0x82, 0x31],
+        function_selector("balanceOf(address)")
+    );
+    assert_eq!(
+        [0xa9, 0x05, 0x9c, 0xbb],
+        function_selector("transfer(address,uint256)")
+    );
+    assert_eq!(
+        [0xdd, 0x62, 0xed, 0x3e],
+        function_selector("allowance(address,address)")
+    );
+    assert_eq!(
+        [0x09, 0x5e, 0xa7, 0xb3],
+        function_selector("approve(address,uint256)")
+    );
+    assert_eq!(
+        [0x23, 0xb8, 0x72, 0xdd],
+        function_selector("transferFrom(address,address,uint256)")
+    );
+}
+
+#[test]
+fn erc721_selectors() {
+    assert_eq!(0x6352211e, function_selector_u32("ownerOf(uint256)"));
+    assert_eq!(
+        0x42842e0e,
+        function_selector_u32("safeTransferFrom(address,address,uint256)")
+    );
+    assert_eq!(
+        0xb88d4fde,
+        function_selector_u32("safeTransferFrom(address,address,uint256,bytes)")
+    );
+    assert_eq!(
+        0xa22cb465,
+        function_selector_u32("setApprovalForAll(address,bool)")
+    );
+    assert_eq!(0x081812fc, function_selector_u32("getApproved(uint256)"));
+    assert_eq!(
+        0xe985e9c5,
+        function_selector_u32("isApprovedForAll(address,address)")
+    );
+    assert_eq!(
+        0x01ffc9a7,
+        function_selector_u32("supportsInterface(bytes4)")
+    );
+}
+
+#[test]
+fn tuple_and_array_selectors() {
+    assert_eq!(
+        0x414bf389,
+        function_selector_u32(
+            "exactInputSingle((address,address,uint24,address,uint256,uint256,uint256,uint160))"
+        )
+    );
+    assert_eq!(
+        0xaed245dc,
+        function_selector_u32("f(uint256[2][],(bool,bytes32)[3],fixed128x18,function)")
+    );
+}
+
+#[test]
+#[should_panic(expected = "must not contain whitespace or parameter names")]
+fn parameter_names() {
+    function_selector("transfer(address to,uint256 amount)");
+}
+
+#[test]
+#[should_panic(expected = "must not contain whitespace or parameter names")]
+fn spaces() {
+    function_selector("transfer(address, uint256)");
+}
+
+#[test]
+#[should_panic(expected = "`uint` must be written as `uint256`")]
+fn uint_alias() {
+    function_selector("transfer(address,uint)");
+}
+
+#[test]
+#[should_panic(expected = "`int` must be written as `int256`")]
+fn int_alias() {
+    function_selector("f(int[])");
+}
+
+#[test]
+#[should_panic(expected = "`byte` must be written as `bytes1`")]
+fn byte_alias() {
+    function_selector("f(byte)");
+}
+
+#[test]
+#[should_panic(expected = "unknown type")]
+fn invalid_integer_size() {
+    function_selector("f(uint7)");
+}
+
+#[test]
+#[should_panic(expected = "unknown type")]
+fn invalid_bytes_size() {
+    function_selector("f(bytes33)");
+}
+
+#[test]
+#[should_panic(expected = "unknown type")]
+fn empty_parameter() {
+    function_selector("f(uint256,)");
+}
+
+#[test]
+#[should_panic(expected = "invalid array length")]
+fn zero_array_length() {
+    function_selector("f(uint256[0])");
+}
+
+#[test]
+#[should_panic(expected = "expected `(`")]
+fn missing_parameters() {
+    function_selector("totalSupply");
+}
+
+#[test]
+#[should_panic(expected = "unexpected characters after `)`")]
+fn trailing_characters() {
+    function_selector("f()returns(uint256)");
+}
+
+#[test]
+fn erc20_event_topics() {
+    const TRANSFER: [u8; 32] = event_topic("Transfer(address,address,uint256)");
+
+    assert_eq!(
+        [
+            0xdd, 0xf2, 0x52, 0xad, 0x1b, 0xe2, 0xc8, 0x9b, 0x69, 0xc2, 0xb0, 0x68, 0xfc, 0x37,
+            0x8d, 0xaa, 0x95, 0x2b, 0xa7, 0xf1, 0x63, 0xc4, 0xa1, 0x16, 0x28, 0xf5, 0x5a, 0x4d,
+            0xf5, 0x23, 0xb3, 0xef,
+        ],
+        TRANSFER,
+    );
+    assert_eq!(
+        [
+            0x8c, 0x5b, 0xe1, 0xe5, 0xeb, 0xec, 0x7d, 0x5b, 0xd1, 0x4f, 0x71, 0x42, 0x7d, 0x1e,
+            0x84, 0xf3, 0xdd, 0x03, 0x14, 0xc0, 0xf7, 0xb2, 0x29, 0x1e, 0x5b, 0x20, 0x0a, 0xc8,
+            0xc7, 0xc3, 0xb9, 0x25,
+        ],
+        event_topic("Approval(address,address,uint256)"),
+    );
+}
+
+#[test]
+fn erc721_event_topics() {
+    assert_eq!(
+        [
+            0x17, 0x30, 0x7e, 0xab, 0x39, 0xab, 0x61, 0x07, 0xe8, 0x89, 0x98, 0x45, 0xad, 0x3d,
+            0x59, 0xbd, 0x96, 0x53, 0xf2, 0x00, 0xf2, 0x20, 0x92, 0x04, 0x89, 0xca, 0x2b, 0x59,
+            0x37, 0x69, 0x6c, 0x31,
+        ],
+        event_topic("ApprovalForAll(address,address,bool)"),
+    );
+}
+
+#[test]
+#[should_panic(expected = "`uint` must be written as `uint256`")]
+fn non_canonical_event() {
+    event_topic("Transfer(address,address,uint)");
+}
+
+#[test]
+fn error_selectors() {
+    const ERROR: [u8; 4] = error_selector("Error(string)");
+
+    assert_eq!([0x08, 0xc3, 0x79, 0xa0], ERROR);
+    assert_eq!([0x4e, 0x48, 0x7b, 0x71], error_selector("Panic(uint256)"));
+    assert_eq!(
+        [0xe4, 0x50, 0xd3, 0x8c],
+        error_selector("ERC20InsufficientBalance(address,uint256,uint256)")
+    );
+}
+
+#[test]
+fn indexed_value_types() {
+    let mut address = [0; 32];
+    address[12..].copy_from_slice(&[0xab; 20]);
+    assert_eq!(address, indexed_address(&Address([0xab; 20])));
+
+    let mut uint = [0; 32];
+    uint[24..].copy_from_slice(&1_000_000_007u64.to_be_bytes());
+    assert_eq!(uint, indexed_uint(1_000_000_007));
+
+    let mut int = [0xff; 32];
+    int[31] = 0xfe;
+    assert_eq!(int, indexed_int(-2));
+    assert_eq!(indexed_uint(5), indexed_int(5));
+
+    assert_eq!([0xff; 32], indexed_u256(&U256([0xff; 32])));
+    assert_eq!(uint, indexed_u256(&U256(uint)));
+    assert_eq!(int, indexed_i256(&I256(int)));
+
+    let mut bool_true = [0; 32];
+    bool_true[31] = 1;
+    assert_eq!(bool_true, indexed_bool(true));
+    assert_eq!([0; 32], indexed_bool(false));
+
+    let mut bytes4 = [0; 32];
+    bytes4[..4].copy_from_slice(&[0xde, 0xad, 0xbe, 0xef]);
+    assert_eq!(bytes4, indexed_bytes_n(&[0xde, 0xad, 0xbe, 0xef]));
+}
+
+#[test]
+fn indexed_reference_types() {
+    const HELLO: [u8; 32] = [
+        0x1c, 0x8a, 0xff, 0x95, 0x06, 0x85, 0xc2, 0xed, 0x4b, 0xc3, 0x17, 0x4f, 0x34, 0x72, 0x28,
+        0x7b, 0x56, 0xd9, 0x51, 0x7b, 0x9c, 0x94, 0x81, 0x27, 0x31, 0x9a, 0x09, 0xa7, 0xa3, 0x6d,
+        0xea, 0xc8,
+    ];
+
+    assert_eq!(HELLO, indexed_string("hello"));
+    assert_eq!(HELLO, indexed_bytes(b"hello"));
+    assert_eq!(
+        [
+            0xe9, 0x0b, 0x7b, 0xce, 0xb6, 0xe7, 0xdf, 0x54, 0x18, 0xfb, 0x78, 0xd8, 0xee, 0x54,
+            0x6e, 0x97, 0xc8, 0x3a, 0x08, 0xbb, 0xcc, 0xc0, 0x1a, 0x06, 0x44, 0xd5, 0x99, 0xcc,
+            0xd2, 0xa7, 0xc2, 0xe0,
+        ],
+        indexed_array(&[indexed_uint(1), indexed_uint(2)]),
+    );
+}
+
+/// Declarations with their canonical signatures and method identifiers
+const CORPUS: &[(&str, &str, u32)] = &[
+    (
+        "function transfer(address to, uint amount) external returns (bool)",
+        "transfer(address,uint256)",
+        0xa9059cbb,
+    ),
+    (
+        "function safeTransferFrom(address from, address to, uint256 tokenId, bytes calldata data) external",
+        "safeTransferFrom(address,address,uint256,bytes)",
+        0xb88d4fde,
+    ),
+    (
+        "function safeBatchTransferFrom(address from, address to, uint256[] calldata ids, uint256[] calldata values, bytes calldata data) external",
+        "safeBatchTransferFrom(address,address,uint256[],uint256[],bytes)",
+        0x2eb2c2d6,
+    ),
+    (
+        "function onERC1155Received(address operator, address from, uint256 id, uint256 value, bytes calldata data) external returns (bytes4)",
+        "onERC1155Received(address,address,uint256,uint256,bytes)",
+        0xf23a6e61,
+    ),
+    (
+        "function exactInputSingle(tuple(address tokenIn, address tokenOut, uint24 fee, address recipient, uint256 deadline, uint256 amountIn, uint256 amountOutMinimum, uint160 sqrtPriceLimitX96) params) external payable returns (uint256 amountOut)",
+        "exactInputSingle((address,address,uint24,address,uint256,uint256,uint256,uint160))",
+        0x414bf389,
+    ),
+    ("f((uint,bool)[] x)", "f((uint256,bool)[])", 0x7e51e982),
+    (
+        "function withdraw(address payable recipient, uint amount) public",
+        "withdraw(address,uint256)",
+        0xf3fef3a3,
+    ),
+    (
+        "function setBytes(byte b, bytes32 h, string memory s) public",
+        "setBytes(bytes1,bytes32,string)",
+        0xb46b3a14,
+    ),
+    (
+        "function multicall(bytes[] calldata data) external payable returns (bytes[] memory results)",
+        "multicall(bytes[])",
+        0xac9650d8,
+    ),
+    (
+        "function execute(address dest, uint256 value, bytes calldata func) external",
+        "execute(address,uint256,bytes)",
+        0xb61d27f6,
+    ),
+    (
+        "function f(uint[ 2 ] [] memory a, fixed x)",
+        "f(uint256[2][],fixed128x18)",
+        0x1ff5503a,
+    ),
+    (
+        "function deposit(uint256 assets, address receiver) public returns (uint256 shares)",
+        "deposit(uint256,address)",
+        0x6e553f65,
+    ),
+    (
+        "g(((int a, byte b)[] inner, ufixed c)[3][] memory nested)",
+        "g(((int256,bytes1)[],ufixed128x18)[3][])",
+        0x4d3652bd,
+    ),
+    ("  totalSupply ( )  ", "totalSupply()", 0x18160ddd),
+];
+
+#[test]
+fn signature_corpus() {
+    for &(declaration, canonical, selector) in CORPUS {
+        let signature = Signature::<128>::parse(declaration);
+
+        assert_eq!(canonical, signature.as_str(), "{declaration}");
+        assert_eq!(
+            selector.to_be_bytes(),
+            signature.selector(),
+            "{declaration}"
+        );
+        assert_eq!(
+            Keccak256::new().update(signature.as_bytes()).finalize(),
+            signature.topic(),
+        );
+    }
+}
+
+#[test]
+fn const_signature() {
+    const TRANSFER: Signature<64> =
+        Signature::parse("event Transfer(address indexed from, address indexed to, uint value)");
+    const INSUFFICIENT_BALANCE: Signature<64> =
+        Signature::parse("error InsufficientBalance(uint available, uint required)");
+
+    assert_eq!(
+        event_topic("Transfer(address,address,uint256)"),
+        TRANSFER.topic()
+    );
+    assert_eq!([0xcf, 0x47, 0x91, 0x81], INSUFFICIENT_BALANCE.selector());
+}
+
+#[test]
+#[should_panic(expected = "canonical signature exceeds the buffer")]
+fn signature_buffer_too_small() {
+    Signature::<16>::parse("transfer(address to, uint amount)");
+}
+
+#[test]
+#[should_panic(expected = "unexpected identifier after parameter name")]
+fn signature_two_names() {
+    Signature::<64>::parse("transfer(address to from, uint amount)");
+}
+
+#[test]
+#[should_panic(expected = "expected `,` or `)`")]
+fn signature_unterminated() {
+    Signature::<64>::parse("transfer(address to, uint amount");
+}
+
+#[test]
+#[should_panic(expected = "unknown type")]
+fn signature_unknown_type() {
+    Signature::<64>::parse("transfer(Account to, uint amount)");
+}
+
+#[test]
+fn interface_ids() {
+    const IERC165: [u8; 4] = interface_id(&["supportsInterface(bytes4)"]);
+    const IERC721: [u8; 4] = interface_id(&[
+        "balanceOf(address)",
+        "ownerOf(uint256)",
+        "safeTransferFrom(address,address,uint256,bytes)",
+        "safeTransferFrom(address,address,uint256)",
+        "transferFrom(address,address,uint256)",
+        "approve(address,uint256)",
+        "setApprovalForAll(address,bool)",
+        "getApproved(uint256)",
+        "isApprovedForAll(address,address)",
+    ]);
+    const IERC1155: [u8; 4] = interface_id(&[
+        "safeTransferFrom(address,address,uint256,uint256,bytes)",
+        "safeBatchTransferFrom(address,address,uint256[],uint256[],bytes)",
+        "balanceOf(address,uint256)",
+        "balanceOfBatch(address[],uint256[])",
+        "setApprovalForAll(address,bool)",
+        "isApprovedForAll(address,address)",
+    ]);
+
+    assert_eq!([0x01, 0xff, 0xc9, 0xa7], IERC165);
+    assert_eq!([0x80, 0xac, 0x58, 0xcd], IERC721);
+    assert_eq!([0xd9, 0xb6, 0x7a, 0x26], IERC1155);
+    assert_eq!([0; 4], interface_id(&[]));
+}
+
+#[test]
+#[should_panic(expected = "duplicate signature in interface")]
+fn duplicate_interface_function() {
+    interface_id(&[
+        "balanceOf(address)",
+        "ownerOf(uint256)",
+        "balanceOf(address)",
+    ]);
+}
+
+#[test]
+#[should_panic(expected = "invalid signature: must not contain whitespace or parameter names")]
+fn non_canonical_interface_function() {
+    interface_id(&["supportsInterface(bytes4 interfaceId)"]);
+}
+
+#[test]
+fn erc1820_interface_hashes() {
+    const ERC777_TOKENS_RECIPIENT: [u8; 32] = erc1820_interface_hash("ERC777TokensRecipient");
+
+    assert_eq!(
+        [
+            0xb2, 0x81, 0xfc, 0x8c, 0x12, 0x95, 0x4d, 0x22, 0x54, 0x4d, 0xb4, 0x5d, 0xe3, 0x15,
+            0x9a, 0x39, 0x27, 0x28, 0x95, 0xb1, 0x69, 0xa8, 0x52, 0xb3, 0x14, 0xf9, 0xcc, 0x76,
+            0x2e, 0x44, 0xc5, 0x3b,
+        ],
+        ERC777_TOKENS_RECIPIENT
+    );
+    assert_eq!(
+        [
+            0x29, 0xdd, 0xb5, 0x89, 0xb1, 0xfb, 0x5f, 0xc7, 0xcf, 0x39, 0x49, 0x61, 0xc1, 0xad,
+            0xf5, 0xf8, 0xc6, 0x45, 0x47, 0x61, 0xad, 0xf7, 0x95, 0xe6, 0x7f, 0xe1, 0x49, 0xf6,
+            0x58, 0xab, 0xe8, 0x95,
+        ],
+        erc1820_interface_hash("ERC777TokensSender")
+    );
+}
@@ -0,0 +1,224 @@
+//! Expected values come from the specifications where they exist:
+//!
+//! - the array and long `bytes` data slots are derived from the hashes of slots
+//!   0 to 4, `keccak256(uint256(slot))`
+//! - the EIP-1967 slots are those of EIP-1967 and, for the rollback slot,
+//!   OpenZeppelin Contracts
+//! - the ERC-7201 locations are the example of ERC-7201 and the namespaces of
+//!   OpenZeppelin Contracts 5
+//!
+//! The mapping slots were computed with a separate Python implementation of
+//! Keccak-256, for the layouts described in the comments; they were not read
+//! from solc or a node. The slot of `address(0)` in a mapping at slot 0 is the
+//! Keccak-256 of 64 zero bytes, the widely published first zero hash of
+//! Keccak-256 Merkle trees with zero leaves.
+
+mod common;
+
+use keccak_const::address::Address;
+use keccak_const::sol_type::U256;
+use keccak_const::solidity::indexed_address;
+use keccak_const::storage;
+
+#[test]
+fn dynamic_array_data_slot() {
+    for (slot, expected) in [
+        (
+            0,
+            "290decd9548b62a8d60345a988386fc84ba6bc95484008f6362f93160ef3e563",
+        ),
+        (
+            1,
+            "b10e2d527612073b26eecdfd717e6a320cf44b4afac2b0732d9fcbe2b7fa0cf6",
+        ),
+        (
+            2,
+            "405787fa12a823e0f2b7631cc41b3ba8828b3321ca811111fa75cd3aa3bb5ace",
+        ),
+    ] {
+        assert_eq!(
+            U256(common::hex(expected)),
+            storage::dynamic_array_data_slot(&U256::from_u128(slot))
+        );
+    }
+}
+
+#[test]
+fn mapping_slot() {
+    // mapping(address => uint256) balanceOf; at slot 0
+    const BALANCE: U256 =
+        storage::mapping_slot(&indexed_address(&Address([0x11; 20])), &U256::from_u128(0));
+    // mapping(uint8 => uint256) at slot 5
+    let mut key = [0; 32];
+    key[31] = 7;
+
+    assert_eq!(
+        U256(common::hex(
+            "f043c50fe795c69f30b8ff78b84032dc53a9d87ca283ae10a1dacfbb648e83ef"
+        )),
+        BALANCE
+    );
+    assert_eq!(
+        U256(common::hex(
+            "eddb6698d7c569ff62ff64f1f1492bf14a54594835ba0faac91f84b4f5d81460"
+        )),
+        storage::mapping_slot(&key, &U256::from_u128(5))
+    );
+    assert_eq!(
+        U256(common::hex(
+            "ad3228b676f7d3cd4284a5443f17f1962b36e491b30a40b2405849e597ba5fb5"
+        )),
+        storage::mapping_slot(&indexed_address(&Address([0; 20])), &U256::from_u128(0))
+    );
+}
+
+#[test]
+fn mapping_slot_bytes() {
+    // mapping(string => uint256) at slot 2
+    assert_eq!(
+        U256(common::hex(
+            "98cc3604479d1233834ea19a78b22cff641ec62dc88921ba3f1f66a37957a4f8"
+        )),
+        storage::mapping_slot_bytes(b"hello", &U256::from_u128(2))
+    );
+}
+
+#[test]
+fn nested_mapping_slot() {
+    // mapping(address => mapping(address => uint256)) allowance; at slot 1
+    let owner = indexed_address(&Address([0x11; 20]));
+    let spender = indexed_address(&Address([0x22; 20]));
+
+    assert_eq!(
+        U256(common::hex(
+            "c1c5f965d29f0d4614dc5d7a10929cd88a089f67386275dfd83b6bd3e280c8cd"
+        )),
+        storage::nested_mapping_slot(&[owner, spender], &U256::from_u128(1))
+    );
+    assert_eq!(
+        storage::mapping_slot(
+            &spender,
+            &storage::mapping_slot(&owner, &U256::from_u128(1))
+        ),
+        storage::nested_mapping_slot(&[owner, spender], &U256::from_u128(1))
+    );
+    assert_eq!(
+        U256::from_u128(1),
+        storage::nested_mapping_slot(&[], &U256::from_u128(1))
+    );
+}
+
+#[test]
+fn array_element_slot() {
+    // arrays at slot 3
+    let data = storage::dynamic_array_data_slot(&U256::from_u128(3));
+    let slot_0 = U256(common::hex(
+        "c2575a0e9e593c00f959f8c92f12db2869c3395a3b0502d05e2516446f71f85b",
+    ));
+    let slot_1 = U256(common::hex(
+        "c2575a0e9e593c00f959f8c92f12db2869c3395a3b0502d05e2516446f71f85c",
+    ));
+    let slot_10 = U256(common::hex(
+        "c2575a0e9e593c00f959f8c92f12db2869c3395a3b0502d05e2516446f71f865",
+    ));
+
+    assert_eq!(slot_0, data);
+    // uint8[]
+    assert_eq!((slot_1, 8), storage::array_element_slot(&data, 40, 1));
+    // uint128[]
+    assert_eq!((slot_1, 16), storage::array_element_slot(&data, 3, 16));
+    // uint96[], two elements per slot
+    assert_eq!((slot_1, 12), storage::array_element_slot(&data, 3, 12));
+    // uint256[]
+    assert_eq!((slot_10, 0), storage::array_element_slot(&data, 10, 32));
+    // struct { uint256 a; uint256 b; }[]
+    assert_eq!((slot_10, 0), storage::array_element_slot(&data, 5, 64));
+    // uint8[40] at slot 3
+    assert_eq!(
+        (U256::from_u128(4), 7),
+        storage::array_element_slot(&U256::from_u128(3), 39, 1)
+    );
+}
+
+#[test]
+#[should_panic = "element size must be at most 32 bytes or a multiple of 32"]
+fn invalid_element_size() {
+    storage::array_element_slot(&U256::from_u128(0), 0, 48);
+}
+
+#[test]
+fn long_bytes_data_slot() {
+    // string at slot 4
+    assert_eq!(
+        storage::dynamic_array_data_slot(&U256::from_u128(4)),
+        storage::long_bytes_data_slot(&U256::from_u128(4), 0)
+    );
+    assert_eq!(
+        U256(common::hex(
+            "8a35acfbc15ff81a39ae7d344fd709f28e8600b4aa8c65c6b64bfe7fe36bd19d"
+        )),
+        storage::long_bytes_data_slot(&U256::from_u128(4), 2)
+    );
+}
+
+#[test]
+fn eip1967_slot() {
+    const IMPLEMENTATION: U256 = storage::eip1967_slot("eip1967.proxy.implementation");
+
+    assert_eq!(
+        U256(common::hex(
+            "360894a13ba1a3210667c828492db98dca3e2076cc3735a920a3ca505d382bbc"
+        )),
+        IMPLEMENTATION
+    );
+    assert_eq!(
+        U256(common::hex(
+            "b53127684a568b3173ae13b9f8a6016e243e63b6e8ee1178d6a717850b5d6103"
+        )),
+        storage::eip1967_slot("eip1967.proxy.admin")
+    );
+    assert_eq!(
+        U256(common::hex(
+            "a3f0ad74e5423aebfd80d3ef4346578335a9a72aeaee59ff6cb3582b35133d50"
+        )),
+        storage::eip1967_slot("eip1967.proxy.beacon")
+    );
+    assert_eq!(
+        U256(common::hex(
+            "4910fdfa16fed3260ed0e7147f7cc6da11a60208b5b9406d12a635614ffd9143"
+        )),
+        storage::eip1967_slot("eip1967.proxy.rollback")
+    );
+}
+
+#[test]
+fn erc7201_slot() {
+    // the example of ERC-7201
+    const MAIN: U256 = storage::erc7201_slot("example.main");
+
+    assert_eq!(
+        U256(common::hex(
+            "183a6125c38840424c4a85fa12bab2ab606c4b6d0e7cc73c0c06ba5300eab500"
+        )),
+        MAIN
+    );
+    // OpenZeppelin Contracts 5
+    assert_eq!(
+        U256(common::hex(
+            "52c63247e1f47db19d5ce0460030c497f067ca4cebf71ba98eeadabe20bace00"
+        )),
+        storage::erc7201_slot("openzeppelin.storage.ERC20")
+    );
+    assert_eq!(
+        U256(common::hex(
+            "9016d09d72d40fdae2fd8ceac6b6234c7706214fd39c1cd1e609a0528c199300"
+        )),
+        storage::erc7201_slot("openzeppelin.storage.Ownable")
+    );
+    assert_eq!(
+        U256(common::hex(
+            "f0c57e16840df040f15088dc2f81fe391c3923bec73e23a9662efc9c229c6a00"
+        )),
+        storage::erc7201_slot("openzeppelin.storage.Initializable")
+    );
+}
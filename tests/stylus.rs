@@ -0,0 +1,142 @@
+use keccak_const::solidity::function_selector;
+use keccak_const::stylus;
+use keccak_const::stylus::stylus_selector;
+use keccak_const::stylus_selector;
+
+#[test]
+fn to_camel_case() {
+    const BALANCE_OF: [u8; 9] = stylus::to_camel_case("balance_of");
+
+    assert_eq!(*b"balanceOf", BALANCE_OF);
+    assert_eq!(*b"name", stylus::to_camel_case::<4>("name"));
+    assert_eq!(
+        *b"setApprovalForAll",
+        stylus::to_camel_case::<17>("set_approval_for_all")
+    );
+    assert_eq!(*b"erc20Name", stylus::to_camel_case::<9>("erc20_name"));
+    assert_eq!(*b"tokenUri", stylus::to_camel_case::<8>("_token__uri_"));
+}
+
+#[test]
+#[should_panic(expected = "invalid camelCase length")]
+fn to_camel_case_wrong_length() {
+    stylus::to_camel_case::<10>("balance_of");
+}
+
+#[test]
+fn erc20_methods() {
+    for (name, params, signature) in [
+        ("name", &[][..], "name()"),
+        ("symbol", &[], "symbol()"),
+        ("decimals", &[], "decimals()"),
+        ("total_supply", &[], "totalSupply()"),
+        ("balance_of", &["address"], "balanceOf(address)"),
+        (
+            "transfer",
+            &["address", "uint256"],
+            "transfer(address,uint256)",
+        ),
+        (
+            "transfer_from",
+            &["address", "address", "uint256"],
+            "transferFrom(address,address,uint256)",
+        ),
+        (
+            "approve",
+            &["address", "uint256"],
+            "approve(address,uint256)",
+        ),
+        (
+            "allowance",
+            &["address", "address"],
+            "allowance(address,address)",
+        ),
+    ] {
+        assert_eq!(
+            function_selector(signature),
+            stylus_selector(name, params),
+            "{name}"
+        );
+    }
+}
+
+#[test]
+fn erc721_methods() {
+    for (name, params, signature) in [
+        ("balance_of", &["address"][..], "balanceOf(address)"),
+        ("owner_of", &["uint256"], "ownerOf(uint256)"),
+        (
+            "safe_transfer_from",
+            &["address", "address", "uint256"],
+            "safeTransferFrom(address,address,uint256)",
+        ),
+        (
+            "safe_transfer_from",
+            &["address", "address", "uint256", "bytes"],
+            "safeTransferFrom(address,address,uint256,bytes)",
+        ),
+        (
+            "set_approval_for_all",
+            &["address", "bool"],
+            "setApprovalForAll(address,bool)",
+        ),
+        ("get_approved", &["uint256"], "getApproved(uint256)"),
+        (
+            "is_approved_for_all",
+            &["address", "address"],
+            "isApprovedForAll(address,address)",
+        ),
+        (
+            "supports_interface",
+            &["bytes4"],
+            "supportsInterface(bytes4)",
+        ),
+    ] {
+        assert_eq!(
+            function_selector(signature),
+            stylus_selector(name, params),
+            "{name}"
+        );
+    }
+}
+
+#[test]
+fn selector_override() {
+    const TOKEN_URI: [u8; 4] = stylus_selector!(
+        #[selector(name = "tokenURI")]
+        token_uri("uint256")
+    );
+
+    assert_eq!(function_selector("tokenURI(uint256)"), TOKEN_URI);
+    assert_ne!(TOKEN_URI, stylus_selector!(token_uri("uint256")));
+    assert_eq!(
+        function_selector("tokenUri(uint256)"),
+        stylus_selector!(token_uri("uint256"))
+    );
+}
+
+#[test]
+fn tuple_and_array_parameters() {
+    assert_eq!(
+        function_selector("batchMint((address,uint256)[],bytes32)"),
+        stylus_selector("batch_mint", &["(address,uint256)[]", "bytes32"]),
+    );
+}
+
+#[test]
+#[should_panic(expected = "`uint` must be written as `uint256`")]
+fn non_canonical_parameter() {
+    stylus_selector("balance_of", &["uint"]);
+}
+
+#[test]
+#[should_panic(expected = "parameter is not a canonical type")]
+fn parameter_with_name() {
+    stylus_selector("balance_of", &["address owner"]);
+}
+
+#[test]
+#[should_panic(expected = "invalid method name")]
+fn invalid_method_name() {
+    stylus_selector("balance-of", &["address"]);
+}